@@ -0,0 +1,122 @@
+language-name = ENGLISH
+language-font = pixel
+
+score = { $score }
+discord-details = Komboter is not dying, not today.
+
+## hud
+
+hud-best = BEST { $score }
+hud-new-best = NEW BEST
+hud-distance = { $distance } M
+hud-speed = SPEED { $speed }%
+hud-memory = MEMORY { $memory }%
+hud-power-up = { $name } { $seconds }S
+hud-combo = COMBO x{ $multiplier }
+
+popup = +{ $points } { $name }
+bonus-byte = BYTE
+bonus-near-miss = NEAR MISS
+bonus-airtime = AIRTIME
+bonus-clean-landing = CLEAN LANDING
+
+breakdown-title = RUN SUMMARY
+breakdown-distance = DISTANCE
+breakdown-total = TOTAL
+breakdown-line = { $name }  { $points }
+breakdown-bonus = { $name } x{ $count }  { $points }
+breakdown-combo = BEST COMBO x{ $multiplier }
+
+modifier-cache = CACHE HIT
+modifier-corrupted = CORRUPTED
+
+## milestones
+
+milestone-best = PREVIOUS BEST
+biome-downtown = DOWNTOWN
+biome-industrial = INDUSTRIAL ZONE
+biome-outskirts = OUTSKIRTS
+
+## daily challenge
+
+daily-title = DAILY CHALLENGE { $date }
+daily-modifiers = { $modifiers }
+daily-start = PRESS D TO PLAY, ONE SCORED ATTEMPT PER DAY
+daily-played = ALREADY PLAYED TODAY, PRESS D TO PRACTICE
+daily-leaderboard = BEST DAYS
+daily-entry = { $rank }. { $date }  { $score }
+daily-low-gravity = LOW GRAVITY
+daily-faster-drain = FASTER DRAIN
+daily-no-bytes = NO BYTES
+daily-mirrored-camera = MIRRORED CAMERA
+
+## versus
+
+versus-runner = P{ $runner } { $score }  { $memory }%
+versus-winner = PLAYER { $runner } WINS
+versus-draw = DRAW
+
+## title screen
+
+menu-skin = < SKIN: { $name } >
+menu-palette = < PALETTE: { $name } >
+menu-palette-locked = < PALETTE: { $name } > ({ $locked } LOCKED)
+menu-language = < LANGUAGE: { $name } >
+menu-hint = LEFT/RIGHT SKIN  UP/DOWN PALETTE  L LANGUAGE  SPACE START  D DAILY  V VERSUS
+menu-accessibility-visuals = 1 REDUCED MOTION: { $motion }  2 HIGH CONTRAST: { $contrast }  3 COLORBLIND: { $colorblind }
+menu-accessibility-assists = 4 GAME SPEED: { $speed }%  5 AUTO JUMP: { $jump }
+menu-versus = V VERSUS: P1 SPACE, P2 UP OR GAMEPADS  C SPLIT SCREEN: { $split }
+option-on = ON
+option-off = OFF
+
+skin-default = DEFAULT
+skin-inverted = INVERTED
+palette-original = ORIGINAL
+palette-crimson = CRIMSON
+palette-matrix = MATRIX
+palette-gold = GOLD
+
+## tutorial
+
+key-space = SPACE
+key-enter = ENTER
+tutorial-skip = ESC SKIPS THE TUTORIAL
+tutorial-tap = TAP SPACE FOR A SHORT HOP
+tutorial-hold = HOLD SPACE TO JUMP HIGHER AND FURTHER
+tutorial-coyote-intro = NEXT LEDGE, TRY RUNNING OFF IT BEFORE JUMPING
+tutorial-coyote = NOT TOO LATE, YOU CAN STILL JUMP RIGHT AFTER LEAVING A LEDGE
+tutorial-bytes = GRAB BYTES TO REFILL YOUR MEMORY BEFORE IT RUNS OUT
+tutorial-done = THAT'S ALL, YOU'RE ON YOUR OWN NOW
+
+## achievements
+
+achievement-unlocked = ACHIEVEMENT UNLOCKED
+achievement-kilobyte = KILOBYTE
+achievement-kilobyte-description = REACH A SCORE OF 1000
+achievement-megabyte = MEGABYTE
+achievement-megabyte-description = REACH A SCORE OF 5000
+achievement-hoarder = HOARDER
+achievement-hoarder-description = COLLECT 10 BYTES IN A SINGLE RUN
+achievement-running_on_fumes = RUNNING ON FUMES
+achievement-running_on_fumes-description = SURVIVE 5 SECONDS UNDER 5% MEMORY
+achievement-leap_of_faith = LEAP OF FAITH
+achievement-leap_of_faith-description = CLEAR A GAP AT 90% OF THE MAX SPEED
+
+## dialog
+
+speaker-self = YOU
+speaker-komboter = KOMBOTER
+
+intro-cursed = THAT CURSED KOMBOTER AGAIN... F***!
+intro-booting = I CAN'T BELIEVE IT CAN'T EVEN HANDLE BOOTING UP
+intro-parts = I NEED TO HURRY AND GET THOSE PARTS ASAP
+intro-bsod = WE DON'T WANT ANOTHER BLUE SCREEN...
+first-death = FATAL EXCEPTION. PRESS R TO CONTINUE
+death-streak = ERROR COUNT EXCEEDS EXPECTATIONS
+death-streak-reply = OH SHUT UP
+death-early = NOT AGAIN...
+death-late = SO CLOSE, I COULD SMELL THE RAM
+respawn-focus = ONE MORE TIME. FOCUS.
+memory-warning = LOW MEMORY. CLOSING UNUSED PROCESSES
+far-out = THE PARTS SHOP SHOULD BE AROUND HERE SOMEWHERE
+really-far-out = UPTIME RECORD DETECTED. SUSPICIOUS
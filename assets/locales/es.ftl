@@ -0,0 +1,122 @@
+language-name = ESPAÑOL
+language-font = pixel
+
+score = { $score }
+discord-details = Komboter no se muere, hoy no.
+
+## hud
+
+hud-best = RÉCORD { $score }
+hud-new-best = NUEVO RÉCORD
+hud-distance = { $distance } M
+hud-speed = VELOCIDAD { $speed }%
+hud-memory = MEMORIA { $memory }%
+hud-power-up = { $name } { $seconds }S
+hud-combo = COMBO x{ $multiplier }
+
+popup = +{ $points } { $name }
+bonus-byte = BYTE
+bonus-near-miss = POR LOS PELOS
+bonus-airtime = TIEMPO EN EL AIRE
+bonus-clean-landing = ATERRIZAJE LIMPIO
+
+breakdown-title = RESUMEN
+breakdown-distance = DISTANCIA
+breakdown-total = TOTAL
+breakdown-line = { $name }  { $points }
+breakdown-bonus = { $name } x{ $count }  { $points }
+breakdown-combo = MEJOR COMBO x{ $multiplier }
+
+modifier-cache = CACHÉ
+modifier-corrupted = CORRUPTO
+
+## milestones
+
+milestone-best = RÉCORD ANTERIOR
+biome-downtown = CENTRO
+biome-industrial = ZONA INDUSTRIAL
+biome-outskirts = AFUERAS
+
+## daily challenge
+
+daily-title = RETO DIARIO { $date }
+daily-modifiers = { $modifiers }
+daily-start = PULSA D PARA JUGAR, UN INTENTO PUNTUADO AL DÍA
+daily-played = YA JUGASTE HOY, PULSA D PARA PRACTICAR
+daily-leaderboard = MEJORES DÍAS
+daily-entry = { $rank }. { $date }  { $score }
+daily-low-gravity = GRAVEDAD BAJA
+daily-faster-drain = DRENAJE RÁPIDO
+daily-no-bytes = SIN BYTES
+daily-mirrored-camera = CÁMARA INVERTIDA
+
+## versus
+
+versus-runner = J{ $runner } { $score }  { $memory }%
+versus-winner = GANA EL JUGADOR { $runner }
+versus-draw = EMPATE
+
+## title screen
+
+menu-skin = < ASPECTO: { $name } >
+menu-palette = < PALETA: { $name } >
+menu-palette-locked = < PALETA: { $name } > ({ $locked } BLOQUEADAS)
+menu-language = < IDIOMA: { $name } >
+menu-hint = IZQ/DER ASPECTO  ARRIBA/ABAJO PALETA  L IDIOMA  ESPACIO EMPEZAR  D DIARIO  V VERSUS
+menu-accessibility-visuals = 1 MOVIMIENTO REDUCIDO: { $motion }  2 ALTO CONTRASTE: { $contrast }  3 DALTONISMO: { $colorblind }
+menu-accessibility-assists = 4 VELOCIDAD: { $speed }%  5 SALTO AUTOMÁTICO: { $jump }
+menu-versus = V VERSUS: J1 ESPACIO, J2 ARRIBA O MANDOS  C PANTALLA DIVIDIDA: { $split }
+option-on = SÍ
+option-off = NO
+
+skin-default = CLÁSICO
+skin-inverted = INVERTIDO
+palette-original = ORIGINAL
+palette-crimson = CARMESÍ
+palette-matrix = MATRIX
+palette-gold = ORO
+
+## tutorial
+
+key-space = ESPACIO
+key-enter = ENTER
+tutorial-skip = ESC PARA SALTAR EL TUTORIAL
+tutorial-tap = PULSA ESPACIO PARA UN SALTO CORTO
+tutorial-hold = MANTÉN ESPACIO PARA SALTAR MÁS ALTO Y MÁS LEJOS
+tutorial-coyote-intro = EN LA PRÓXIMA CORNISA, CORRE FUERA ANTES DE SALTAR
+tutorial-coyote = AÚN NO ES TARDE, PUEDES SALTAR JUSTO DESPUÉS DE SALIR DE UNA CORNISA
+tutorial-bytes = RECOGE BYTES PARA RECUPERAR MEMORIA ANTES DE QUE SE ACABE
+tutorial-done = ESO ES TODO, AHORA ESTÁS SOLO
+
+## achievements
+
+achievement-unlocked = LOGRO DESBLOQUEADO
+achievement-kilobyte = KILOBYTE
+achievement-kilobyte-description = ALCANZA 1000 PUNTOS
+achievement-megabyte = MEGABYTE
+achievement-megabyte-description = ALCANZA 5000 PUNTOS
+achievement-hoarder = ACAPARADOR
+achievement-hoarder-description = RECOGE 10 BYTES EN UNA SOLA PARTIDA
+achievement-running_on_fumes = EN LA RESERVA
+achievement-running_on_fumes-description = SOBREVIVE 5 SEGUNDOS CON MENOS DEL 5% DE MEMORIA
+achievement-leap_of_faith = SALTO DE FE
+achievement-leap_of_faith-description = SALVA UN HUECO AL 90% DE LA VELOCIDAD MÁXIMA
+
+## dialog
+
+speaker-self = TÚ
+speaker-komboter = KOMBOTER
+
+intro-cursed = ¡ESE MALDITO KOMBOTER OTRA VEZ... M****!
+intro-booting = NO PUEDO CREER QUE NI SIQUIERA PUEDA ARRANCAR
+intro-parts = TENGO QUE DARME PRISA Y CONSEGUIR ESAS PIEZAS YA
+intro-bsod = NO QUEREMOS OTRA PANTALLA AZUL...
+first-death = EXCEPCIÓN FATAL. PULSA R PARA CONTINUAR
+death-streak = EL NÚMERO DE ERRORES SUPERA LO ESPERADO
+death-streak-reply = OH, CÁLLATE
+death-early = OTRA VEZ NO...
+death-late = TAN CERCA, PODÍA OLER LA RAM
+respawn-focus = UNA VEZ MÁS. CONCÉNTRATE.
+memory-warning = MEMORIA BAJA. CERRANDO PROCESOS INACTIVOS
+far-out = LA TIENDA DE PIEZAS DEBERÍA ESTAR POR AQUÍ
+really-far-out = RÉCORD DE TIEMPO ACTIVO DETECTADO. SOSPECHOSO
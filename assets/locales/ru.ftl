@@ -0,0 +1,122 @@
+language-name = РУССКИЙ
+language-font = fallback
+
+score = { $score }
+discord-details = Комботер не умрёт, не сегодня.
+
+## hud
+
+hud-best = РЕКОРД { $score }
+hud-new-best = НОВЫЙ РЕКОРД
+hud-distance = { $distance } М
+hud-speed = СКОРОСТЬ { $speed }%
+hud-memory = ПАМЯТЬ { $memory }%
+hud-power-up = { $name } { $seconds }С
+hud-combo = КОМБО x{ $multiplier }
+
+popup = +{ $points } { $name }
+bonus-byte = БАЙТ
+bonus-near-miss = НА ГРАНИ
+bonus-airtime = ПОЛЁТ
+bonus-clean-landing = ЧИСТОЕ ПРИЗЕМЛЕНИЕ
+
+breakdown-title = ИТОГИ ЗАБЕГА
+breakdown-distance = ДИСТАНЦИЯ
+breakdown-total = ВСЕГО
+breakdown-line = { $name }  { $points }
+breakdown-bonus = { $name } x{ $count }  { $points }
+breakdown-combo = ЛУЧШЕЕ КОМБО x{ $multiplier }
+
+modifier-cache = КЭШ
+modifier-corrupted = СБОЙ
+
+## milestones
+
+milestone-best = ПРОШЛЫЙ РЕКОРД
+biome-downtown = ЦЕНТР
+biome-industrial = ПРОМЗОНА
+biome-outskirts = ОКРАИНА
+
+## daily challenge
+
+daily-title = ЕЖЕДНЕВНОЕ ИСПЫТАНИЕ { $date }
+daily-modifiers = { $modifiers }
+daily-start = НАЖМИ D, ОДНА ЗАСЧИТАННАЯ ПОПЫТКА В ДЕНЬ
+daily-played = СЕГОДНЯ УЖЕ СЫГРАНО, НАЖМИ D ДЛЯ ТРЕНИРОВКИ
+daily-leaderboard = ЛУЧШИЕ ДНИ
+daily-entry = { $rank }. { $date }  { $score }
+daily-low-gravity = НИЗКАЯ ГРАВИТАЦИЯ
+daily-faster-drain = БЫСТРАЯ УТЕЧКА
+daily-no-bytes = БЕЗ БАЙТОВ
+daily-mirrored-camera = ЗЕРКАЛЬНАЯ КАМЕРА
+
+## versus
+
+versus-runner = И{ $runner } { $score }  { $memory }%
+versus-winner = ПОБЕДИЛ ИГРОК { $runner }
+versus-draw = НИЧЬЯ
+
+## title screen
+
+menu-skin = < ОБЛИК: { $name } >
+menu-palette = < ПАЛИТРА: { $name } >
+menu-palette-locked = < ПАЛИТРА: { $name } > (ЗАКРЫТО: { $locked })
+menu-language = < ЯЗЫК: { $name } >
+menu-hint = ВЛЕВО/ВПРАВО ОБЛИК  ВВЕРХ/ВНИЗ ПАЛИТРА  L ЯЗЫК  ПРОБЕЛ СТАРТ  D ИСПЫТАНИЕ  V ДУЭЛЬ
+menu-accessibility-visuals = 1 МЕНЬШЕ ДВИЖЕНИЯ: { $motion }  2 КОНТРАСТ: { $contrast }  3 ДАЛЬТОНИЗМ: { $colorblind }
+menu-accessibility-assists = 4 СКОРОСТЬ ИГРЫ: { $speed }%  5 АВТОПРЫЖОК: { $jump }
+menu-versus = V ДУЭЛЬ: И1 ПРОБЕЛ, И2 ВВЕРХ ИЛИ ГЕЙМПАДЫ  C РАЗДЕЛЁННЫЙ ЭКРАН: { $split }
+option-on = ВКЛ
+option-off = ВЫКЛ
+
+skin-default = ОБЫЧНЫЙ
+skin-inverted = ИНВЕРСИЯ
+palette-original = ИСХОДНАЯ
+palette-crimson = БАГРОВАЯ
+palette-matrix = МАТРИЦА
+palette-gold = ЗОЛОТАЯ
+
+## tutorial
+
+key-space = ПРОБЕЛ
+key-enter = ENTER
+tutorial-skip = ESC ПРОПУСКАЕТ ОБУЧЕНИЕ
+tutorial-tap = НАЖМИ ПРОБЕЛ ДЛЯ КОРОТКОГО ПРЫЖКА
+tutorial-hold = ДЕРЖИ ПРОБЕЛ, ЧТОБЫ ПРЫГНУТЬ ВЫШЕ И ДАЛЬШЕ
+tutorial-coyote-intro = НА СЛЕДУЮЩЕМ КРАЮ ПОПРОБУЙ СБЕЖАТЬ С НЕГО ДО ПРЫЖКА
+tutorial-coyote = ЕЩЁ НЕ ПОЗДНО, ПРЫГНУТЬ МОЖНО СРАЗУ ПОСЛЕ КРАЯ
+tutorial-bytes = СОБИРАЙ БАЙТЫ, ЧТОБЫ ПОПОЛНИТЬ ПАМЯТЬ
+tutorial-done = ВОТ И ВСЁ, ДАЛЬШЕ САМ
+
+## achievements
+
+achievement-unlocked = ДОСТИЖЕНИЕ ОТКРЫТО
+achievement-kilobyte = КИЛОБАЙТ
+achievement-kilobyte-description = НАБЕРИ 1000 ОЧКОВ
+achievement-megabyte = МЕГАБАЙТ
+achievement-megabyte-description = НАБЕРИ 5000 ОЧКОВ
+achievement-hoarder = БАРАХОЛЬЩИК
+achievement-hoarder-description = СОБЕРИ 10 БАЙТОВ ЗА ОДИН ЗАБЕГ
+achievement-running_on_fumes = НА ПОСЛЕДНЕМ ДЫХАНИИ
+achievement-running_on_fumes-description = ПРОДЕРЖИСЬ 5 СЕКУНД С ПАМЯТЬЮ НИЖЕ 5%
+achievement-leap_of_faith = ПРЫЖОК ВЕРЫ
+achievement-leap_of_faith-description = ПЕРЕПРЫГНИ ПРОПАСТЬ НА 90% МАКСИМАЛЬНОЙ СКОРОСТИ
+
+## dialog
+
+speaker-self = ТЫ
+speaker-komboter = КОМБОТЕР
+
+intro-cursed = ОПЯТЬ ЭТОТ ПРОКЛЯТЫЙ КОМБОТЕР... Ч****!
+intro-booting = НЕ ВЕРЮ, ЧТО ОН ДАЖЕ ЗАГРУЗИТЬСЯ НЕ МОЖЕТ
+intro-parts = НАДО СРОЧНО ДОСТАТЬ ЗАПЧАСТИ
+intro-bsod = НАМ НЕ НУЖЕН ЕЩЁ ОДИН СИНИЙ ЭКРАН...
+first-death = ФАТАЛЬНАЯ ОШИБКА. НАЖМИТЕ R, ЧТОБЫ ПРОДОЛЖИТЬ
+death-streak = КОЛИЧЕСТВО ОШИБОК ПРЕВЫШАЕТ ОЖИДАНИЯ
+death-streak-reply = ДА ЗАМОЛЧИ ТЫ
+death-early = ТОЛЬКО НЕ СНОВА...
+death-late = ТАК БЛИЗКО, Я ПОЧТИ ЧУЯЛ ЗАПАХ ОПЕРАТИВКИ
+respawn-focus = ЕЩЁ РАЗ. СОБЕРИСЬ.
+memory-warning = МАЛО ПАМЯТИ. ЗАКРЫВАЮ НЕИСПОЛЬЗУЕМЫЕ ПРОЦЕССЫ
+far-out = МАГАЗИН ЗАПЧАСТЕЙ ДОЛЖЕН БЫТЬ ГДЕ-ТО ЗДЕСЬ
+really-far-out = ОБНАРУЖЕН РЕКОРД АПТАЙМА. ПОДОЗРИТЕЛЬНО
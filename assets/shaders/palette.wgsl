@@ -0,0 +1,31 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+const MAX_SWAPS: u32 = 8u;
+const TOLERANCE: f32 = 0.0004;
+
+struct Palette {
+    // uv rect of the current atlas frame, min in xy and max in zw
+    frame: vec4<f32>,
+    from: array<vec4<f32>, MAX_SWAPS>,
+    to: array<vec4<f32>, MAX_SWAPS>,
+    swaps: u32,
+};
+
+@group(2) @binding(0) var<uniform> palette: Palette;
+@group(2) @binding(1) var sheet: texture_2d<f32>;
+@group(2) @binding(2) var sheet_sampler: sampler;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = mix(palette.frame.xy, palette.frame.zw, mesh.uv);
+    var color = textureSample(sheet, sheet_sampler, uv);
+
+    for (var i = 0u; i < min(palette.swaps, MAX_SWAPS); i++) {
+        if distance(color.rgb, palette.from[i].rgb) < TOLERANCE {
+            color = vec4<f32>(palette.to[i].rgb, color.a);
+            break;
+        }
+    }
+
+    return color;
+}
@@ -0,0 +1,91 @@
+//! Sums up the runs recorded with telemetry turned on.
+//!
+//! `cargo run --bin telemetry -- [--bins N] [FILE|FOLDER]...`, the game's telemetry
+//! folder is read when no path is given.
+
+use std::{collections::BTreeMap, path::PathBuf, process::ExitCode};
+
+use glib::telemetry::{self, Histogram, RunRecord};
+
+const DEFAULT_BINS: usize = 12;
+const BAR_COLUMNS: usize = 48;
+
+fn main() -> ExitCode {
+    let mut bins = DEFAULT_BINS;
+    let mut paths = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bins" => match args.next().and_then(|bins| bins.parse().ok()) {
+                Some(count) => bins = count,
+                None => {
+                    eprintln!("--bins takes a number");
+                    return ExitCode::FAILURE;
+                }
+            },
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(telemetry::dir());
+    }
+
+    let mut runs = Vec::new();
+    for path in paths.iter() {
+        match RunRecord::read(path) {
+            Ok(read) => runs.extend(read),
+            Err(err) => {
+                eprintln!("could not read {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if runs.is_empty() {
+        eprintln!("no runs recorded yet");
+        return ExitCode::FAILURE;
+    }
+
+    println!("{} runs\n", runs.len());
+
+    let mut causes = BTreeMap::<&str, usize>::new();
+    for run in runs.iter() {
+        *causes.entry(run.death_cause.as_str()).or_default() += 1;
+    }
+
+    println!("death causes");
+    for (cause, count) in causes {
+        println!("{cause:>16} {count:>6}");
+    }
+
+    let per_run = |value: fn(&RunRecord) -> f32| runs.iter().map(value).collect::<Vec<_>>();
+    let flattened = |values: fn(&RunRecord) -> &[f32]| {
+        runs.iter()
+            .flat_map(|run| values(run).iter().copied())
+            .collect::<Vec<_>>()
+    };
+
+    for (title, values) in [
+        ("score", per_run(|run| run.score)),
+        ("death x", per_run(|run| run.death_x)),
+        ("difficulty", per_run(|run| run.difficulty)),
+        ("jumps", per_run(|run| run.jumps as f32)),
+        ("bytes", per_run(|run| run.bytes as f32)),
+        ("memory", flattened(|run| &run.memory)),
+        ("gap sizes", flattened(|run| &run.gaps)),
+    ] {
+        let Some(histogram) = Histogram::new(&values, bins) else {
+            continue;
+        };
+
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        println!(
+            "\n{title} (mean {mean:.1})\n{}",
+            histogram.render(BAR_COLUMNS)
+        );
+    }
+
+    ExitCode::SUCCESS
+}
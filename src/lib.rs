@@ -1,4 +1,5 @@
 pub mod bsod;
+pub mod telemetry;
 use std::time::Duration;
 
 use bevy::prelude::*;
@@ -13,12 +14,20 @@ pub static DISCORD_STATE: &str = "b̵̰̑̊̋̕r̷̜͕͍͚̗̠̈́͆́ű̵̎̀͐
 pub const APP_WINDOW_NAME: &str = "Malformed";
 pub const APP_WINDOW_MIN_WIDTH: f32 = 600.0;
 pub const APP_WINDOW_MIN_HEIGHT: f32 = 400.0;
+pub const APP_WINDOW_WIDTH: f32 = 1280.0;
+pub const APP_WINDOW_HEIGHT: f32 = 720.0;
+// virtual resolution the world and the ui are laid out for
 pub const APP_WINDOW_DESIRED_WITH: f32 = 1920.0;
 pub const APP_WINDOW_DESIRED_HEIGHT: f32 = 1080.0;
 
 pub const WORLD_BACKGROUND_COLOR: Color = Color::rgb(164.0 / 255.0, 206.0 / 255.0, 215.0 / 255.0);
 pub const WORLD_SPRITE_SCALE: Vec3 = Vec3::new(2.0, 2.0, 1.0);
 
+// simulation
+// gameplay and physics ticks per second, independent of the frame rate
+pub const SIMULATION_TICK_RATE: f64 = 60.0;
+pub const SIMULATION_TIMESTEP: f32 = (1.0 / SIMULATION_TICK_RATE) as f32;
+
 // player
 pub const PLAYER_SCALE_X: f32 = WORLD_SPRITE_SCALE.x;
 pub const PLAYER_SCALE_Y: f32 = WORLD_SPRITE_SCALE.y;
@@ -31,10 +40,13 @@ pub const PLAYER_COYOTE_JUMP_TIME: f32 = 0.35;
 pub const PLAYER_JUMP_BUFFERING_TIME: f32 = 0.3;
 pub const PLAYER_JUMP_HEIGHT: f32 = 200.0;
 pub const PLAYER_WALKING_TIMER: Duration = Duration::from_secs(10);
+pub const PLAYER_SPAWN_X: f32 = -40.0;
 pub const PLAYER_INIT_VELOCITY_X: f32 = 100.0;
 pub const PLAYER_INIT_ACCELERATION_X: f32 = 80.0;
 pub const PLAYER_RESPAWN_VELOCITY: f32 = (PLAYER_INIT_VELOCITY_X + PLAYER_VELOCITY_BUMP) * 2.0;
 pub const PLAYER_MAX_VELOCITY_X: f32 = 1500.0;
+// runners cover this many times their velocity
+pub const PLAYER_VELOCITY_SCALE: f32 = 2.0;
 pub const PLAYER_VELOCITY_BUMP: f32 = 150.0;
 pub const PLAYER_JUMP_WINDOW: f32 = 0.35;
 
@@ -42,29 +54,32 @@ pub const PLAYER_MAX_MEMORY: f32 = 100.0;
 pub const PLAYER_MEMORY_DRAINING_RATE: f32 = 1.0;
 pub const PLAYER_MEMORY_REGEN_RATE: f32 = 3.0;
 pub const PLAYER_MEMORY_SHARDS_SPAWN_RATE_MODIFIER: f32 = 1.0 / 2.0;
-
-pub static DIALOG_LINES: [(&str, Duration); 4] = [
-    (
-        "THAT CURSED KOMBOTER AGAIN... F***!",
-        Duration::from_secs(3),
-    ),
-    (
-        "I CAN'T BELIEVE IT CAN'T EVEN HANDLE BOOTING UP",
-        Duration::from_secs(3),
-    ),
-    (
-        "I NEED TO HURRY AND GET THOSE PARTS ASAP",
-        Duration::from_secs(3),
-    ),
-    (
-        "WE DON'T WANT ANOTHER BLUE SCREEN...",
-        Duration::from_secs(2),
-    ),
-];
+pub const PLAYER_MEMORY_WARNING: f32 = PLAYER_MAX_MEMORY * 0.25;
+pub const PLAYER_MEMORY_CRITICAL: f32 = PLAYER_MAX_MEMORY * 0.1;
+// extra drain at full speed, as a fraction of the base one
+pub const PLAYER_MEMORY_SPEED_DRAIN: f32 = 1.0;
+pub const PLAYER_MEMORY_CACHE_DURATION: Duration = Duration::from_secs(3);
+pub const PLAYER_MEMORY_CACHE_DRAIN: f32 = 0.5;
+pub const PLAYER_MEMORY_CORRUPTION_DURATION: Duration = Duration::from_secs(2);
+pub const PLAYER_MEMORY_CORRUPTION_DRAIN: f32 = 2.0;
+pub const PLAYER_SCORE_MILESTONE: f32 = 500.0;
+
+pub const PLAYER_PALETTE_MAX_SWAPS: usize = 8;
+
+// dialog
+pub const DIALOG_TYPEWRITER_SPEED: f32 = 40.0;
+pub const DIALOG_BLIP_EVERY: usize = 3;
 
 // camera
 pub const CAMERA_STARTING_POSITIION: Vec2 = Vec2::new(0.0, 128.0);
 pub const CAMERA_PLAYER_OFFSET: Vec2 = Vec2::new(512.0, 256.0);
+pub const CAMERA_LOOK_AHEAD: f32 = 256.0;
+pub const CAMERA_DEAD_ZONE_Y: f32 = 48.0;
+pub const CAMERA_SHAKE_MAX_OFFSET: Vec2 = Vec2::new(24.0, 16.0);
+pub const CAMERA_SHAKE_MAX_ANGLE: f32 = 0.03;
+pub const CAMERA_TRAUMA_DECAY: f32 = 1.2;
+pub const CAMERA_PUNCH_DECAY: f32 = 0.02;
+pub const CAMERA_HARD_LANDING_VELOCITY: f32 = 900.0;
 
 // terrain
 pub const PLATFORMS_MAX_Y: f32 = PLAYER_JUMP_HEIGHT * 0.4 + PLATFORMS_MIN_Y;
@@ -76,9 +91,96 @@ pub const WORLD_MAX_PLATFORMS: u8 = 10;
 pub const BUILDING_WIDTH: f32 = 220.0;
 pub const BUILDING_HEIGHT: f32 = 260.0;
 
+// distance from the origin the leading runner gets to before the world is shifted back
+pub const ORIGIN_REBASE_DISTANCE: f32 = 20_000.0;
+// how far behind the last runner scenery gets despawned
+pub const SCENERY_DESPAWN_DISTANCE: f32 = 8_000.0;
+// buildings and bytes kept aside for reuse instead of despawned, per kind
+pub const TERRAIN_POOL_CAPACITY: usize = 16;
+
 pub const RTE_X: f32 = 0.0;
 pub const RTE_Y: f32 = PLATFORMS_MIN_Y + WORLD_SPRITE_SCALE.y * 130.0 + 65.0;
 
+// particles
+pub const PARTICLES_Z: f32 = 15.0;
+pub const PARTICLES_PIXEL_STEP: usize = 2;
+pub const PARTICLES_TRAIL_VELOCITY: f32 = 600.0;
+
+// audio
+pub const SFX_PITCH_VARIATION: f64 = 0.08;
+pub const SFX_MEMORY_BEEP_INTERVAL: Duration = Duration::from_millis(1200);
+pub const MUSIC_MAX_SPEEDUP: f64 = 0.15;
+pub const MUSIC_LOW_PASS_CUTOFF: f32 = 700.0;
+
+// tutorial
+pub const TUTORIAL_LINE_DURATION: Duration = Duration::from_secs(4);
+pub const TUTORIAL_BYTE_DISTANCE: f32 = 400.0;
+
+// achievements
+pub const ACHIEVEMENT_TOAST_DURATION: Duration = Duration::from_secs(4);
+
+// score
+pub const SCORE_BYTE_BONUS: f32 = 25.0;
+// how far from the edge of a platform a jump still counts as a near miss
+pub const SCORE_NEAR_MISS_DISTANCE: f32 = 48.0;
+pub const SCORE_NEAR_MISS_BONUS: f32 = 40.0;
+// seconds in the air before a landing pays off
+pub const SCORE_AIRTIME_MIN: f32 = 0.8;
+pub const SCORE_AIRTIME_BONUS: f32 = 30.0;
+pub const SCORE_CLEAN_LANDING_BONUS: f32 = 10.0;
+pub const SCORE_COMBO_STEP: f32 = 0.25;
+pub const SCORE_COMBO_MAX: f32 = 4.0;
+pub const SCORE_COMBO_WINDOW: Duration = Duration::from_secs(3);
+// multiplier lost every second once the combo window is over
+pub const SCORE_COMBO_DECAY: f32 = 0.5;
+pub const SCORE_POPUP_DURATION: Duration = Duration::from_secs(1);
+pub const SCORE_POPUP_RISE: f32 = 80.0;
+
+// milestones
+pub const MILESTONE_MARKER_Y: f32 = PLATFORMS_MAX_Y + 260.0;
+pub const MILESTONE_MARKER_Z: f32 = 5.0;
+// world units ahead of the player markers get placed at
+pub const MILESTONE_SPAWN_AHEAD: f32 = 3000.0;
+
+// daily challenge
+pub const DAILY_LOW_GRAVITY: f32 = 0.6;
+pub const DAILY_FASTER_DRAIN: f32 = 1.5;
+pub const DAILY_LEADERBOARD_SIZE: usize = 5;
+
+// versus
+// runners trailing the leader by more than it covers in this many seconds are out
+pub const VERSUS_MAX_LAG: f32 = 0.75;
+// never any closer than this, runners start out a few steps apart
+pub const VERSUS_MIN_GAP: f32 = 240.0;
+pub const VERSUS_SPAWN_SPACING: f32 = 60.0;
+// room kept around the trailing runner by a shared camera
+pub const VERSUS_CAMERA_MARGIN: f32 = 128.0;
+
+// hud
+pub const HUD_PIXELS_PER_METER: f32 = 40.0;
+pub const HUD_COMPACT_WIDTH: f32 = 900.0;
+// pulses per second of the memory meter once under the warning level
+pub const HUD_PULSE_RATE: f32 = 1.5;
+
+// accessibility
+pub const ACCESSIBILITY_GAME_SPEEDS: [f32; 4] = [1.0, 0.85, 0.7, 0.5];
+pub const ACCESSIBILITY_REDUCED_PARALLAX: f32 = 0.25;
+pub const ACCESSIBILITY_OUTLINE_COLOR: Color = Color::rgb(1.0, 0.9, 0.0);
+pub const ACCESSIBILITY_OUTLINE_WIDTH: f32 = 4.0;
+pub const ACCESSIBILITY_OUTLINE_DEPTH: f32 = 96.0;
+// seconds before reaching the edge of a platform the assisted jump fires
+pub const ACCESSIBILITY_AUTO_JUMP_LEAD: f32 = 0.1;
+
+// telemetry
+// seconds between two samples of the memory left
+pub const TELEMETRY_SAMPLE_INTERVAL: f32 = 1.0;
+
+// bot
+// seconds before the edge of a platform the bot takes off
+pub const BOT_JUMP_LEAD: f32 = 0.05;
+// how far into the next platform the bot aims its landings
+pub const BOT_LANDING_AIM: f32 = 0.25;
+
 // background
 pub const BACKGROUND_IMAGE_WIDTH: f32 = 4608.0;
 pub const BACKGROUND_LAYER_Y: f32 = 512.0;
@@ -87,15 +189,25 @@ pub const BACKGROUND_LAYER_SACLE: f32 = 2.0;
 
 //
 pub mod utils {
-    use crate::{APP_WINDOW_DESIRED_HEIGHT, APP_WINDOW_DESIRED_WITH};
-    use bevy::prelude::*;
-
-    pub fn rescale(transform: &mut Transform, width: f32, height: f32) {
-        transform.scale *= Vec3::new(
-            width / APP_WINDOW_DESIRED_WITH,
-            height / APP_WINDOW_DESIRED_HEIGHT,
-            0.0,
-        );
+    use std::path::PathBuf;
+
+    use crate::{APP_WINDOW_DESIRED_HEIGHT, APP_WINDOW_DESIRED_WITH, APP_WINDOW_NAME};
+
+    /// per user directory the game keeps its files in
+    pub fn data_dir() -> PathBuf {
+        std::env::var_os("APPDATA")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("XDG_DATA_HOME").map(PathBuf::from))
+            .or_else(|| {
+                std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share"))
+            })
+            .unwrap_or_default()
+            .join(APP_WINDOW_NAME)
+    }
+
+    /// scale that fits the virtual resolution inside a `width` x `height` window
+    pub fn virtual_scale(width: f32, height: f32) -> f32 {
+        (width / APP_WINDOW_DESIRED_WITH).min(height / APP_WINDOW_DESIRED_HEIGHT)
     }
 
     pub mod easings {
@@ -8,16 +8,31 @@ fn main() {
     App::new()
         .add_plugins(GameAssetsPlugin)
         .add_plugins(GameEssentialsPlugin)
+        .add_plugins(GameTimestepPlugin)
+        .add_plugins(GameSettingsPlugin)
+        .add_plugins(GameLocalePlugin)
+        .add_plugins(GameAccessibilityPlugin)
+        .add_plugins(GameBotPlugin)
         .add_plugins(GameAnimationPlugin)
         .add_plugins(GameCameraPlugin)
         .add_plugins(GameSoundTrack)
+        .add_plugins(GameSfxPlugin)
+        .add_plugins(GameAchievementsPlugin)
         .add_plugins(GameMenuPlugin)
+        .add_plugins(GameDailyPlugin)
+        .add_plugins(GameTelemetryPlugin)
+        .add_plugins(GameVersusPlugin)
         .add_plugins(GameRestartPlugin)
         .add_plugins(BackgroundPlugin)
         .add_plugins(TerrainPlugin)
         .add_plugins(BytesPlugin)
+        .add_plugins(ParticlesPlugin)
         .add_plugins(PlayerPlugin)
         .add_plugins(RPCPlugin)
         .add_plugins(DialogPlugin)
+        .add_plugins(GameHudPlugin)
+        .add_plugins(GameMilestonesPlugin)
+        .add_plugins(GameNarrativePlugin)
+        .add_plugins(GameTutorialPlugin)
         .run();
 }
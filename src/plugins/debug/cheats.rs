@@ -0,0 +1,295 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_egui::{egui, EguiContexts};
+use bevy_rapier2d::prelude::*;
+
+use crate::plugins::entities::{
+    bytes::{Byte, BytesPlugin},
+    camera::MainCamera,
+    player::*,
+    terrain::{Building, Parked, Platform, Pool},
+};
+use crate::{GameSpeed, GameState, GameplaySet, Restart, SpriteLayouts, TextureAssets};
+use glib::*;
+
+/// What a click in the world drops under the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheatSpawn {
+    #[default]
+    Nothing,
+    Byte,
+    /// the smallest building there is
+    Obstacle,
+}
+
+/// Shortcuts for tuning, getting to top speed or down a death path without playing
+/// all the way there.
+#[derive(Resource, Debug)]
+pub struct Cheats {
+    /// runners neither fall out of the world nor run out of memory
+    pub god_mode: bool,
+    pub speed: f32,
+    pub memory: f32,
+    pub time_scale: f32,
+    pub spawn: CheatSpawn,
+}
+
+impl Default for Cheats {
+    fn default() -> Self {
+        Self {
+            god_mode: false,
+            speed: PLAYER_MAX_VELOCITY_X,
+            memory: PLAYER_MAX_MEMORY,
+            time_scale: 1.0,
+            spawn: CheatSpawn::Nothing,
+        }
+    }
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub enum Cheat {
+    /// runners stay at that speed until it's cleared with `None`
+    Speed(Option<f32>),
+    Memory(f32),
+    TimeScale(f32),
+    SkipIntro,
+    /// every runner dies on the spot, `Being::Alive` to `Being::Dead`
+    Kill,
+    /// a new run starts right away, `Being::Dead` to `Being::Alive`
+    Revive,
+    /// world position of the click
+    Drop(CheatSpawn, Vec2),
+}
+
+pub struct CheatsPlugin;
+
+impl Plugin for CheatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Cheats>()
+            .add_event::<Cheat>()
+            .add_systems(
+                Update,
+                (Self::panel, Self::click, (Self::apply, Self::drop)).chain(),
+            )
+            .add_systems(
+                FixedUpdate,
+                Self::god
+                    .before(GameplaySet)
+                    .run_if(|cheats: Res<Cheats>| cheats.god_mode)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(Being::Alive)),
+            );
+    }
+}
+
+impl CheatsPlugin {
+    fn panel(mut contexts: EguiContexts, mut cheats: ResMut<Cheats>, mut sent: EventWriter<Cheat>) {
+        let cheats = &mut *cheats;
+
+        egui::Window::new("Cheats").show(contexts.ctx_mut(), |ui| {
+            ui.checkbox(&mut cheats.god_mode, "god mode");
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::Slider::new(&mut cheats.speed, 0.0..=PLAYER_MAX_VELOCITY_X).text("speed"),
+                );
+                if ui.button("hold").clicked() {
+                    sent.send(Cheat::Speed(Some(cheats.speed)));
+                }
+                if ui.button("release").clicked() {
+                    sent.send(Cheat::Speed(None));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::Slider::new(&mut cheats.memory, 0.0..=PLAYER_MAX_MEMORY).text("memory"),
+                );
+                if ui.button("set").clicked() {
+                    sent.send(Cheat::Memory(cheats.memory));
+                }
+            });
+
+            let time_scale =
+                ui.add(egui::Slider::new(&mut cheats.time_scale, 0.1..=3.0).text("time scale"));
+            if time_scale.changed() {
+                sent.send(Cheat::TimeScale(cheats.time_scale));
+            }
+
+            if ui.button("skip the walking intro").clicked() {
+                sent.send(Cheat::SkipIntro);
+            }
+
+            ui.horizontal(|ui| {
+                ui.label("click drops");
+                ui.radio_value(&mut cheats.spawn, CheatSpawn::Nothing, "nothing");
+                ui.radio_value(&mut cheats.spawn, CheatSpawn::Byte, "a byte");
+                ui.radio_value(&mut cheats.spawn, CheatSpawn::Obstacle, "an obstacle");
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Alive to Dead").clicked() {
+                    sent.send(Cheat::Kill);
+                }
+                if ui.button("Dead to Alive").clicked() {
+                    sent.send(Cheat::Revive);
+                }
+            });
+        });
+    }
+
+    /// Clicks that egui did not take, mapped through the camera under the cursor.
+    fn click(
+        mut contexts: EguiContexts,
+        cheats: Res<Cheats>,
+        mouse: Res<ButtonInput<MouseButton>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+        mut sent: EventWriter<Cheat>,
+    ) {
+        if cheats.spawn == CheatSpawn::Nothing
+            || !mouse.just_pressed(MouseButton::Left)
+            || contexts.ctx_mut().wants_pointer_input()
+        {
+            return;
+        }
+
+        let Some(cursor) = window.get_single().ok().and_then(Window::cursor_position) else {
+            return;
+        };
+
+        let position = camera.iter().find_map(|(camera, transform)| {
+            let viewport = camera.logical_viewport_rect()?;
+            if !viewport.contains(cursor) {
+                return None;
+            }
+            camera.viewport_to_world_2d(transform, cursor - viewport.min)
+        });
+
+        if let Some(position) = position {
+            sent.send(Cheat::Drop(cheats.spawn, position));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn apply(
+        mut commands: Commands,
+        mut cheats: EventReader<Cheat>,
+        mut player: Query<
+            (
+                Entity,
+                &Runner,
+                &Transform,
+                &TextureAtlas,
+                &Handle<Image>,
+                &mut Memory,
+                &mut WalkingTimer,
+            ),
+            With<Player>,
+        >,
+        mut next_being: ResMut<NextState<Being>>,
+        mut next_movement: ResMut<NextState<MovementType>>,
+        mut died: EventWriter<Died>,
+        mut restart: EventWriter<Restart>,
+        mut speed: ResMut<GameSpeed>,
+        mut held: ResMut<SpeedOverride>,
+    ) {
+        for cheat in cheats.read() {
+            match *cheat {
+                Cheat::TimeScale(scale) => speed.debug = scale,
+                Cheat::Speed(Some(speed)) => {
+                    if let Err(err) = held.hold(speed) {
+                        warn!("{err}");
+                    }
+                }
+                Cheat::Speed(None) => held.0 = None,
+                Cheat::Revive => {
+                    restart.send(Restart);
+                }
+                Cheat::SkipIntro => next_movement.set(MovementType::Running),
+                Cheat::Kill => next_being.set(Being::Dead),
+                _ => {}
+            }
+
+            for (entity, runner, transform, atlas, sheet, mut memory, mut walking) in
+                player.iter_mut()
+            {
+                match *cheat {
+                    Cheat::Memory(value) => memory.set(value),
+                    Cheat::SkipIntro => {
+                        let remaining = walking.0.remaining();
+                        walking.0.tick(remaining);
+                    }
+                    Cheat::Kill => PlayerPlugin::die(
+                        &mut commands,
+                        &mut died,
+                        entity,
+                        Died {
+                            runner: runner.0,
+                            position: transform.translation,
+                            frame: atlas.index,
+                            sheet: sheet.clone(),
+                            cause: DeathCause::Forced,
+                        },
+                    ),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Bytes float above the building under the click, obstacles sit right on it.
+    #[allow(clippy::too_many_arguments)]
+    fn drop(
+        mut commands: Commands,
+        mut cheats: EventReader<Cheat>,
+        platforms: Query<(Entity, &Platform)>,
+        textures: Res<TextureAssets>,
+        layouts: Res<SpriteLayouts>,
+        mut bytes: ResMut<Pool<Byte>>,
+        mut buildings: ResMut<Pool<Building>>,
+        mut parked: Query<&mut Building, With<Parked>>,
+    ) {
+        for cheat in cheats.read() {
+            match *cheat {
+                Cheat::Drop(CheatSpawn::Byte, position) => {
+                    let Some((building, platform)) = platforms.iter().find(|(_, platform)| {
+                        (position.x - platform.coords.0).abs() <= platform.width / 2.0
+                    }) else {
+                        continue;
+                    };
+
+                    let x = (position.x - platform.coords.0) / WORLD_SPRITE_SCALE.x;
+                    BytesPlugin::place(&mut commands, &textures, &layouts, &mut bytes, building, x);
+                }
+                Cheat::Drop(CheatSpawn::Obstacle, position) => {
+                    Building::spawn(
+                        &mut commands,
+                        &textures,
+                        &mut buildings,
+                        &mut parked,
+                        0,
+                        position.x,
+                        position.y,
+                        10.0,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Tops memory up and catches runners right before they would fall to death.
+    fn god(mut player: Query<(&mut Transform, &mut Velocity, &mut Memory), With<Player>>) {
+        for (mut transform, mut velocity, mut memory) in player.iter_mut() {
+            let max = memory.max;
+            memory.set(max);
+
+            if transform.translation.y < PLATFORMS_MIN_Y {
+                transform.translation.y = PLATFORMS_MAX_Y + BUILDING_HEIGHT * WORLD_SPRITE_SCALE.y;
+                velocity.linvel.y = 0.0;
+            }
+        }
+    }
+}
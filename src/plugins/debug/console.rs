@@ -0,0 +1,311 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use bevy::{ecs::system::SystemId, input::InputSystem, prelude::*};
+use bevy_egui::{
+    egui::{
+        self,
+        text::{CCursor, CCursorRange},
+    },
+    EguiContexts,
+};
+
+use crate::plugins::entities::player::PlayerPlugin;
+
+// lines of output kept around
+const SCROLLBACK: usize = 200;
+
+/// What a command prints back, errors get shown the same way.
+pub type CommandResult = Result<String, String>;
+
+pub struct ConsoleCommand {
+    pub name: &'static str,
+    pub usage: &'static str,
+    /// completions offered for the first argument
+    pub args: &'static [&'static str],
+    system: SystemId<Vec<String>, CommandResult>,
+}
+
+/// Every command the console knows about, plugins add theirs through
+/// `ConsoleApp::console_command`.
+#[derive(Resource, Default)]
+pub struct ConsoleCommands {
+    commands: Vec<ConsoleCommand>,
+}
+
+impl ConsoleCommands {
+    fn find(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// Full lines `line` could be completed to, commands first then their arguments.
+    pub fn complete(&self, line: &str) -> Vec<String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let typing = !line.ends_with(' ');
+
+        match (words.as_slice(), typing) {
+            ([], _) => Vec::new(),
+            ([name], true) => self
+                .commands
+                .iter()
+                .filter(|command| command.name.starts_with(name))
+                .map(|command| command.name.to_owned())
+                .collect(),
+            ([name], false) | ([name, _], true) => {
+                let prefix = words.get(1).copied().unwrap_or_default();
+
+                self.find(name)
+                    .map(|command| {
+                        command
+                            .args
+                            .iter()
+                            .filter(|arg| arg.starts_with(prefix))
+                            .map(|arg| format!("{name} {arg}"))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn help(&self) -> String {
+        self.commands
+            .iter()
+            .map(|command| command.usage)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn run(&self, world: &mut World, line: &str) -> CommandResult {
+        let mut words = line.split_whitespace();
+
+        let Some(name) = words.next() else {
+            return Ok(String::new());
+        };
+
+        if name == "help" {
+            return Ok(self.help());
+        }
+
+        let command = self
+            .find(name)
+            .ok_or_else(|| format!("unknown command `{name}`, `help` lists them all"))?;
+        let args = words.map(str::to_owned).collect();
+
+        world
+            .run_system_with_input(command.system, args)
+            .map_err(|err| format!("{name} failed: {err:?}"))?
+    }
+}
+
+/// Parses the `index`th argument of a command, `usage` is shown when it is missing
+/// or malformed.
+pub fn arg<T: FromStr>(args: &[String], index: usize, usage: &str) -> Result<T, String> {
+    args.get(index)
+        .and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| format!("usage: {usage}"))
+}
+
+pub trait ConsoleApp {
+    /// Registers `system` as the console command `name`, it gets the words typed after
+    /// the name and prints back whatever it returns. Does nothing in release builds.
+    fn console_command<M>(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        args: &'static [&'static str],
+        system: impl IntoSystem<Vec<String>, CommandResult, M> + 'static,
+    ) -> &mut Self;
+}
+
+impl ConsoleApp for App {
+    fn console_command<M>(
+        &mut self,
+        name: &'static str,
+        usage: &'static str,
+        args: &'static [&'static str],
+        system: impl IntoSystem<Vec<String>, CommandResult, M> + 'static,
+    ) -> &mut Self {
+        // release builds have no console to type them in
+        if !cfg!(debug_assertions) {
+            return self;
+        }
+
+        let system = self.world.register_system(system);
+
+        let mut registry = self
+            .world
+            .get_resource_or_insert_with(ConsoleCommands::default);
+        registry.commands.push(ConsoleCommand {
+            name,
+            usage,
+            args,
+            system,
+        });
+        registry.commands.sort_by_key(|command| command.name);
+
+        self
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct Console {
+    pub open: bool,
+    input: String,
+    lines: VecDeque<String>,
+    submitted: Option<String>,
+}
+
+impl Console {
+    fn print(&mut self, text: &str) {
+        for line in text.lines() {
+            if self.lines.len() == SCROLLBACK {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(line.to_owned());
+        }
+    }
+}
+
+/// Drop-down console opened with the backtick key.
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Console>()
+            .init_resource::<ConsoleCommands>()
+            .add_systems(
+                PreUpdate,
+                Self::capture
+                    .after(InputSystem)
+                    .before(PlayerPlugin::read_controls),
+            )
+            .add_systems(Update, (Self::panel, Self::execute).chain());
+    }
+}
+
+impl ConsolePlugin {
+    /// Toggles the console, while it is open the game does not see the keyboard.
+    fn capture(mut console: ResMut<Console>, mut keys: ResMut<ButtonInput<KeyCode>>) {
+        if keys.just_pressed(KeyCode::Backquote) {
+            console.open = !console.open;
+        }
+
+        if console.open {
+            keys.reset_all();
+        }
+    }
+
+    fn panel(
+        mut contexts: EguiContexts,
+        mut console: ResMut<Console>,
+        commands: Res<ConsoleCommands>,
+    ) {
+        if !console.open {
+            return;
+        }
+
+        let console = &mut *console;
+        let ctx = contexts.ctx_mut();
+
+        egui::TopBottomPanel::top("console").show(ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(240.0)
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for line in console.lines.iter() {
+                        ui.monospace(line);
+                    }
+                });
+
+            let input = egui::TextEdit::singleline(&mut console.input)
+                .code_editor()
+                .desired_width(f32::INFINITY)
+                .show(ui);
+            input.response.request_focus();
+
+            // the key opening the console and the one completing get typed in as well
+            console.input.retain(|c| c != '`' && c != '\t');
+
+            let candidates = commands.complete(&console.input);
+
+            if ui.input(|i| i.key_pressed(egui::Key::Tab)) {
+                if let Some(candidate) = candidates.first() {
+                    console.input = format!("{candidate} ");
+
+                    let mut state = input.state;
+                    state
+                        .cursor
+                        .set_char_range(Some(CCursorRange::one(CCursor::new(
+                            console.input.chars().count(),
+                        ))));
+                    state.store(ui.ctx(), input.response.id);
+                }
+            }
+
+            if input.response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                console.submitted = Some(std::mem::take(&mut console.input));
+            }
+
+            if !candidates.is_empty() {
+                ui.weak(candidates.join("    "));
+            }
+        });
+    }
+
+    fn execute(world: &mut World) {
+        let Some(line) = world.resource_mut::<Console>().submitted.take() else {
+            return;
+        };
+
+        let reply = world
+            .resource_scope(|world, commands: Mut<ConsoleCommands>| commands.run(world, &line));
+
+        let mut console = world.resource_mut::<Console>();
+        console.print(&format!("> {line}"));
+
+        match reply {
+            Ok(reply) => console.print(&reply),
+            Err(err) => console.print(&err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_console {
+    use super::*;
+
+    fn registry() -> ConsoleCommands {
+        let mut world = World::new();
+        let mut command = |name, args| ConsoleCommand {
+            name,
+            usage: name,
+            args,
+            system: world.register_system(|In(_): In<Vec<String>>| Ok(String::new())),
+        };
+
+        ConsoleCommands {
+            commands: vec![
+                command("seed", &[]),
+                command("spawn", &["byte", "obstacle"]),
+                command("speed", &[]),
+                command("state", &["Alive", "Dead"]),
+            ],
+        }
+    }
+
+    #[test]
+    fn completes_commands_then_their_arguments() {
+        let registry = registry();
+
+        assert_eq!(registry.complete("s"), ["seed", "spawn", "speed", "state"]);
+        assert_eq!(registry.complete("sp"), ["spawn", "speed"]);
+        assert_eq!(
+            registry.complete("spawn "),
+            ["spawn byte", "spawn obstacle"]
+        );
+        assert_eq!(registry.complete("state D"), ["state Dead"]);
+        assert!(registry.complete("seed ").is_empty());
+        assert!(registry.complete("state Dead ").is_empty());
+    }
+}
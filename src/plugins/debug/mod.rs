@@ -0,0 +1,251 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use bevy_egui::EguiContext;
+use bevy_kira_audio::AudioInstance;
+use bevy_rapier2d::prelude::*;
+
+pub use bevy_inspector_egui::prelude::*;
+pub use bevy_inspector_egui::quick::{
+    FilterQueryInspectorPlugin, StateInspectorPlugin, WorldInspectorPlugin,
+};
+
+use crate::*;
+
+use super::entities::background::BackgroundPlugin;
+use super::entities::bytes::{Byte, BytesPlugin};
+use super::entities::camera::{CameraSystem, Focus};
+use super::entities::player::{Being, MovementType, PlayerPlugin};
+use super::entities::terrain::{BuildingsPlugin, FloatingOriginPlugin, Parked, Platform, Scenery};
+
+mod cheats;
+mod console;
+mod profiler;
+
+pub use cheats::*;
+pub use console::*;
+pub use profiler::*;
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(debug_assertions)]
+        {
+            app.add_plugins(WorldInspectorPlugin::default())
+                .add_plugins(RapierDebugRenderPlugin::default())
+                .add_plugins(FrameTimeDiagnosticsPlugin)
+                .add_systems(Update, Self::inspector_ui)
+                .add_plugins(StateInspectorPlugin::<GameState>::default())
+                .add_plugins(StateInspectorPlugin::<GameAssetsState>::default())
+                .add_plugins(StateInspectorPlugin::<Focus>::default())
+                .add_plugins(StateInspectorPlugin::<Being>::default())
+                .add_plugins(StateInspectorPlugin::<MovementType>::default())
+                .add_plugins(StateInspectorPlugin::<MusicState>::default())
+                .add_plugins(ProfilerPlugin)
+                .add_plugins(CheatsPlugin)
+                .add_plugins(ConsolePlugin)
+                .profile_after(
+                    "gameplay",
+                    FixedUpdate,
+                    GameplaySet,
+                    GameGroundCheckPlugin::<Ground>::check_ground,
+                )
+                .profile_after(
+                    "player collect",
+                    FixedUpdate,
+                    PlayerPlugin::collect,
+                    PlayerPlugin::jump,
+                )
+                .profile_after(
+                    "buildings generate",
+                    FixedUpdate,
+                    BuildingsPlugin::generate,
+                    FloatingOriginPlugin::rebase,
+                )
+                .profile_after(
+                    "bytes spawn",
+                    FixedUpdate,
+                    BytesPlugin::spawn,
+                    BuildingsPlugin::generate,
+                )
+                .profile_after(
+                    "rapier sync",
+                    FixedUpdate,
+                    PhysicsSet::SyncBackend,
+                    GameplaySet,
+                )
+                .profile_after(
+                    "rapier step",
+                    FixedUpdate,
+                    PhysicsSet::StepSimulation,
+                    PhysicsSet::SyncBackend,
+                )
+                .profile_after(
+                    "rapier writeback",
+                    FixedUpdate,
+                    PhysicsSet::Writeback,
+                    PhysicsSet::StepSimulation,
+                )
+                .profile("buildings despawn", Update, BuildingsPlugin::despawn)
+                .profile("camera follow", Update, CameraSystem::Follow)
+                .profile_after(
+                    "camera effects",
+                    Update,
+                    CameraSystem::Effects,
+                    BackgroundPlugin::update,
+                )
+                .profile_after(
+                    "transform propagate",
+                    PostUpdate,
+                    TransformSystem::TransformPropagate,
+                    UiSystem::Layout,
+                );
+        }
+    }
+}
+
+impl DebugPlugin {
+    fn inspector_ui(world: &mut World) {
+        use bevy_egui::egui::*;
+
+        let mut egui_context = world
+            .query_filtered::<&mut EguiContext, With<PrimaryWindow>>()
+            .single(world)
+            .clone();
+
+        let counts = [
+            ("Platform", Self::count::<Platform>(world)),
+            ("Byte", Self::count::<Byte>(world)),
+            ("Scenery", Self::count::<Scenery>(world)),
+            ("Parked", Self::count::<Parked>(world)),
+            ("Entities", world.entities().len() as usize),
+        ];
+        let sounds = world
+            .get_resource::<Assets<AudioInstance>>()
+            .map_or(0, |instances| instances.len());
+
+        Window::new("Performance").show(egui_context.get_mut(), |ui| {
+            ScrollArea::both().show(ui, |ui| {
+                if let Some(diagnostics) = world.get_resource::<DiagnosticsStore>() {
+                    if let Some(fps) = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS) {
+                        if let Some(value) = fps.smoothed() {
+                            ui.label(format!("FPS: {}", value));
+                        }
+                    }
+                }
+
+                let mut profiler = world.resource_mut::<Profiler>();
+
+                Self::frame_graph(ui, &profiler.frames);
+                let over = profiler
+                    .frames
+                    .iter()
+                    .filter(|frame| **frame > FRAME_BUDGET_MS)
+                    .count();
+                ui.label(format!(
+                    "over the {FRAME_BUDGET_MS:.1} ms budget: {over} of {} frames",
+                    profiler.frames.len()
+                ));
+
+                ui.separator();
+                Grid::new("spans").striped(true).show(ui, |ui| {
+                    ui.label("span");
+                    ui.label("mean ms");
+                    ui.label("worst ms");
+                    ui.end_row();
+
+                    for span in profiler.spans.iter() {
+                        ui.label(span.name);
+                        ui.label(format!("{:.3}", span.mean()));
+                        ui.label(format!("{:.3}", span.worst()));
+                        ui.end_row();
+                    }
+                });
+
+                ui.separator();
+                Grid::new("counts").striped(true).show(ui, |ui| {
+                    for (marker, count) in counts {
+                        ui.label(marker);
+                        ui.label(count.to_string());
+                        ui.end_row();
+                    }
+
+                    ui.label("Audio instances");
+                    ui.label(sounds.to_string());
+                    ui.end_row();
+                });
+
+                ui.separator();
+                if profiler.tracing() {
+                    ui.label("tracing the run, written out once it ends");
+                } else {
+                    ui.checkbox(&mut profiler.trace_next_run, "trace the next run");
+                }
+            });
+        });
+    }
+
+    fn count<T: Component>(world: &mut World) -> usize {
+        world.query_filtered::<(), With<T>>().iter(world).count()
+    }
+
+    /// A bar per frame, red past the budget drawn as the line halfway up.
+    fn frame_graph(ui: &mut bevy_egui::egui::Ui, frames: &VecDeque<f32>) {
+        use bevy_egui::egui::*;
+
+        let (rect, _) = ui.allocate_exact_size(vec2(HISTORY as f32, 64.0), Sense::hover());
+        let painter = ui.painter_at(rect);
+        let scale = rect.height() / (FRAME_BUDGET_MS * 2.0);
+
+        painter.rect_filled(rect, 0.0, Color32::from_black_alpha(160));
+
+        for (i, frame) in frames.iter().enumerate() {
+            let color = match *frame > FRAME_BUDGET_MS {
+                true => Color32::LIGHT_RED,
+                false => Color32::LIGHT_GREEN,
+            };
+            let top = rect.bottom() - (frame * scale).min(rect.height());
+
+            painter.vline(
+                rect.left() + i as f32,
+                top..=rect.bottom(),
+                Stroke::new(1.0, color),
+            );
+        }
+
+        painter.hline(
+            rect.x_range(),
+            rect.bottom() - FRAME_BUDGET_MS * scale,
+            Stroke::new(1.0, Color32::YELLOW),
+        );
+    }
+}
+
+pub struct EntityInspector<E> {
+    marker: PhantomData<E>,
+}
+
+impl<E> Default for EntityInspector<E> {
+    fn default() -> Self {
+        Self {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E: 'static> Plugin for EntityInspector<E>
+where
+    E: Component,
+{
+    fn build(&self, app: &mut App) {
+        #[cfg(debug_assertions)]
+        {
+            app.add_plugins(FilterQueryInspectorPlugin::<With<E>>::default());
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::{
+    collections::VecDeque,
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, InternedSystemSet, ScheduleLabel},
+    prelude::*,
+};
+use serde_json::{json, Value};
+
+use crate::plugins::entities::player::Being;
+use glib::{utils, SIMULATION_TICK_RATE};
+
+const TRACES_DIR: &str = "traces";
+// frames kept around for the graph and the averages
+pub const HISTORY: usize = 240;
+// a frame per gameplay tick
+pub const FRAME_BUDGET_MS: f32 = 1000.0 / SIMULATION_TICK_RATE as f32;
+
+pub struct Span {
+    pub name: &'static str,
+    begun: Option<Instant>,
+    frame: Duration,
+    /// milliseconds spent per frame
+    pub history: VecDeque<f32>,
+}
+
+impl Span {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            begun: None,
+            frame: Duration::ZERO,
+            history: VecDeque::with_capacity(HISTORY),
+        }
+    }
+
+    pub fn mean(&self) -> f32 {
+        self.history.iter().sum::<f32>() / self.history.len().max(1) as f32
+    }
+
+    pub fn worst(&self) -> f32 {
+        self.history.iter().copied().fold(0.0, f32::max)
+    }
+}
+
+/// Wall time of the frames and of the sets and systems registered through
+/// `ProfileApp::profile`. Spans in `FixedUpdate` add up over the ticks of a frame.
+#[derive(Resource)]
+pub struct Profiler {
+    epoch: Instant,
+    /// milliseconds per frame
+    pub frames: VecDeque<f32>,
+    pub spans: Vec<Span>,
+    /// events of the run being traced, in the chrome trace format
+    trace: Option<Vec<Value>>,
+    pub trace_next_run: bool,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self {
+            epoch: Instant::now(),
+            frames: VecDeque::with_capacity(HISTORY),
+            spans: Vec::new(),
+            trace: None,
+            trace_next_run: false,
+        }
+    }
+}
+
+impl Profiler {
+    pub fn tracing(&self) -> bool {
+        self.trace.is_some()
+    }
+
+    fn begin(&mut self, span: usize) {
+        self.spans[span].begun = Some(Instant::now());
+    }
+
+    fn end(&mut self, span: usize) {
+        let epoch = self.epoch;
+        let span = &mut self.spans[span];
+
+        let Some(begun) = span.begun.take() else {
+            return;
+        };
+
+        let elapsed = begun.elapsed();
+        span.frame += elapsed;
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(json!({
+                "name": span.name,
+                "ph": "X",
+                "ts": (begun - epoch).as_micros() as u64,
+                "dur": elapsed.as_micros() as u64,
+                "pid": 0,
+                "tid": 0,
+            }));
+        }
+    }
+
+    fn push(history: &mut VecDeque<f32>, value: f32) {
+        if history.len() == HISTORY {
+            history.pop_front();
+        }
+        history.push_back(value);
+    }
+
+    fn path() -> PathBuf {
+        let stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        utils::data_dir()
+            .join(TRACES_DIR)
+            .join(format!("trace-{stamp}.json"))
+    }
+
+    /// Writes the traced run where chrome://tracing or Perfetto can open it.
+    fn dump(events: Vec<Value>) -> std::io::Result<PathBuf> {
+        let path = Self::path();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(
+            &path,
+            serde_json::to_string(&json!({ "traceEvents": events }))?,
+        )?;
+        Ok(path)
+    }
+}
+
+/// Times `set` in `schedule` under `name`, from before its first system starts to
+/// after its last one is done. Works for single systems too.
+pub trait ProfileApp {
+    /// For sets nothing else is ordered ahead of, whatever the executor picks to run
+    /// first still lands in the span.
+    fn profile<M>(
+        &mut self,
+        name: &'static str,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self;
+
+    /// The span only starts once `after` is done, leaving the systems that have to
+    /// run ahead of `set` out of it.
+    fn profile_after<M, N>(
+        &mut self,
+        name: &'static str,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+        after: impl IntoSystemSet<N>,
+    ) -> &mut Self;
+}
+
+impl ProfileApp for App {
+    fn profile<M>(
+        &mut self,
+        name: &'static str,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+    ) -> &mut Self {
+        profile(
+            self,
+            name,
+            schedule.intern(),
+            set.into_system_set().intern(),
+            None,
+        )
+    }
+
+    fn profile_after<M, N>(
+        &mut self,
+        name: &'static str,
+        schedule: impl ScheduleLabel,
+        set: impl IntoSystemSet<M>,
+        after: impl IntoSystemSet<N>,
+    ) -> &mut Self {
+        profile(
+            self,
+            name,
+            schedule.intern(),
+            set.into_system_set().intern(),
+            Some(after.into_system_set().intern()),
+        )
+    }
+}
+
+fn profile<'a>(
+    app: &'a mut App,
+    name: &'static str,
+    schedule: InternedScheduleLabel,
+    set: InternedSystemSet,
+    after: Option<InternedSystemSet>,
+) -> &'a mut App {
+    let mut profiler = app.world.get_resource_or_insert_with(Profiler::default);
+    let span = profiler.spans.len();
+    profiler.spans.push(Span::new(name));
+
+    let begin = (move |mut profiler: ResMut<Profiler>| profiler.begin(span)).before(set);
+    let begin = match after {
+        Some(after) => begin.after(after),
+        None => begin,
+    };
+
+    app.add_systems(schedule, begin).add_systems(
+        schedule,
+        (move |mut profiler: ResMut<Profiler>| profiler.end(span)).after(set),
+    )
+}
+
+pub struct ProfilerPlugin;
+
+impl Plugin for ProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Profiler>()
+            .add_systems(First, Self::frame)
+            .add_systems(OnEnter(Being::Alive), Self::start_trace)
+            .add_systems(OnEnter(Being::Dead), Self::dump_trace);
+    }
+}
+
+impl ProfilerPlugin {
+    fn frame(mut profiler: ResMut<Profiler>, time: Res<Time<Real>>) {
+        let profiler = &mut *profiler;
+        let frame = time.delta_seconds() * 1000.0;
+
+        Profiler::push(&mut profiler.frames, frame);
+
+        for span in profiler.spans.iter_mut() {
+            Profiler::push(&mut span.history, span.frame.as_secs_f32() * 1000.0);
+            span.frame = Duration::ZERO;
+        }
+
+        let ts = profiler.epoch.elapsed().as_micros() as u64;
+        if let Some(trace) = profiler.trace.as_mut() {
+            trace.push(json!({
+                "name": "frame",
+                "ph": "C",
+                "ts": ts,
+                "pid": 0,
+                "args": { "ms": frame },
+            }));
+        }
+    }
+
+    fn start_trace(mut profiler: ResMut<Profiler>) {
+        if profiler.trace_next_run {
+            profiler.trace_next_run = false;
+            profiler.trace = Some(Vec::new());
+        }
+    }
+
+    fn dump_trace(mut profiler: ResMut<Profiler>) {
+        let Some(events) = profiler.trace.take() else {
+            return;
+        };
+
+        match Profiler::dump(events) {
+            Ok(path) => info!("trace of the run written to {}", path.display()),
+            Err(err) => warn!("failed to write the trace: {err}"),
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use crate::plugins::entities::player::*;
+#![allow(clippy::type_complexity)]
+
+use crate::plugins::entities::{camera::*, terrain::ScrollSpeed};
 use crate::plugins::game::prelude::*;
 use bevy::{prelude::*, sprite::Anchor};
 
 use glib::*;
 
+/// Plain color filling in under the layers.
 #[derive(Component)]
 pub struct Background;
 
@@ -11,12 +14,17 @@ pub struct Background;
 pub struct Layer;
 
 #[derive(Component, Reflect)]
-struct Depth(usize);
+pub(crate) struct Depth(usize);
+
+/// How far a layer drifted behind the cameras, wrapping around every frame of the image.
+#[derive(Component, Reflect, Default)]
+pub(crate) struct Parallax(f32);
 
 #[derive(Bundle)]
 struct LayerBundle {
     sprite: SpriteBundle,
     depth: Depth,
+    parallax: Parallax,
     name: Name,
 }
 
@@ -37,6 +45,7 @@ impl LayerBundle {
                 ..Default::default()
             },
             depth: Depth(depth),
+            parallax: Parallax::default(),
             name: Name::new(name),
         }
     }
@@ -49,11 +58,12 @@ impl Plugin for BackgroundPlugin {
         app.add_systems(
             Update,
             Self::update
-                .run_if(in_state(GameState::Resumed))
-                .run_if(in_state(Being::Alive)),
+                .after(CameraSystem::Follow)
+                .before(CameraSystem::Effects)
+                .run_if(in_state(GameState::Resumed)),
         );
 
-        app.register_type::<Depth>();
+        app.register_type::<Depth>().register_type::<Parallax>();
     }
 }
 
@@ -80,31 +90,53 @@ impl BackgroundPlugin {
                 },
                 ..Default::default()
             })
-            .insert(Name::new("Background Patch"));
+            .insert(Name::new("Background Patch"))
+            .insert(Background);
 
         for (depth, (name, texture)) in bg_images.iter().enumerate() {
             commands
                 .spawn(LayerBundle::new(name, texture.clone(), depth + 1))
-                .insert(Layer)
-                .insert(Responsive);
+                .insert(Layer);
         }
     }
 
-    fn update(
-        player_velocity: Query<&AuxiliaryVelocity, With<Player>>,
-        mut layers: Query<(&mut Transform, &Depth), With<Layer>>,
+    /// Keeps the layers around the cameras, drifting behind at a fraction of the pace
+    /// the further away they are.
+    pub(crate) fn update(
+        scroll: Res<ScrollSpeed>,
+        mut layers: Query<(&mut Transform, &Depth, &mut Parallax), With<Layer>>,
+        mut patch: Query<&mut Transform, (With<Background>, Without<Layer>)>,
+        cameras: Query<&Transform, (With<MainCamera>, Without<Layer>, Without<Background>)>,
         time: Res<Time>,
+        settings: Res<Settings>,
     ) {
-        if let Ok(velocity) = player_velocity.get_single() {
-            for (mut transform, depth) in layers.iter_mut() {
-                let frame = (transform.scale.x / 3.0) * BACKGROUND_IMAGE_WIDTH;
-                if transform.translation.x <= frame * -1.0 {
-                    transform.translation.x = frame;
-                }
-
-                transform.translation.x -=
-                    (velocity.value.x / (depth.0 as f32)) * time.delta_seconds();
+        let speed = match settings.accessibility.reduced_motion {
+            true => ACCESSIBILITY_REDUCED_PARALLAX,
+            false => 1.0,
+        };
+
+        // split screen cameras share the layers, they're never far apart
+        let Some(anchor) = cameras
+            .iter()
+            .map(|transform| transform.translation.x)
+            .reduce(|a, b| a + b)
+            .map(|sum| sum / cameras.iter().len() as f32)
+        else {
+            return;
+        };
+
+        for (mut transform, depth, mut parallax) in layers.iter_mut() {
+            let frame = (transform.scale.x / 3.0) * BACKGROUND_IMAGE_WIDTH;
+            if parallax.0 <= frame * -1.0 {
+                parallax.0 = frame;
             }
+
+            parallax.0 -= (speed * scroll.0 / (depth.0 as f32)) * time.delta_seconds();
+            transform.translation.x = anchor + parallax.0;
+        }
+
+        for mut transform in patch.iter_mut() {
+            transform.translation.x = anchor;
         }
     }
 }
@@ -1,7 +1,10 @@
 #![allow(clippy::type_complexity)]
 
-use super::terrain::{BuildingsPlugin, Platform};
-use crate::{Animation, AuxiliaryVelocity, GameAssetsState, Player, SpriteLayouts, TextureAssets};
+use super::terrain::{BuildingsPlugin, Platform, Pool, ScrollSpeed, WorldRng};
+use crate::{
+    Animation, DailyChallenge, DailyModifier, GameAssetsState, GameplaySet, Player, SpriteLayouts,
+    TextureAssets,
+};
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use std::time::Duration;
@@ -34,10 +37,11 @@ pub struct PreventByte;
 pub struct BytesPlugin;
 impl Plugin for BytesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
+        app.init_resource::<Pool<Byte>>().add_systems(
+            FixedUpdate,
             (Self::spawn, Self::float)
                 .chain()
+                .in_set(GameplaySet)
                 .after(BuildingsPlugin::generate)
                 .run_if(in_state(GameAssetsState::Loaded)),
         );
@@ -48,65 +52,104 @@ const MAX_FLOATING_Y: f32 = BUILDING_HEIGHT / 2.0 + 15.0;
 const MIN_FLOATING_Y: f32 = BUILDING_HEIGHT / 2.0 + 10.0;
 
 impl BytesPlugin {
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn(
         mut commands: Commands,
         platform_query: Query<(Entity, &Platform), Without<PreventByte>>,
-        player_query: Query<&AuxiliaryVelocity, With<Player>>,
+        player_query: Query<(), With<Player>>,
+        scroll: Res<ScrollSpeed>,
         textures: Res<TextureAssets>,
         layouts: Res<SpriteLayouts>,
+        challenge: Res<DailyChallenge>,
+        mut rng: ResMut<WorldRng>,
+        mut pool: ResMut<Pool<Byte>>,
     ) {
-        if platform_query.is_empty() {
+        if platform_query.is_empty() || challenge.has(DailyModifier::NoBytes) {
             return;
         }
 
-        let Ok(velocity) = player_query.get_single() else {
+        if player_query.is_empty() {
             return;
-        };
+        }
+
+        let rng = &mut rng.0;
 
         for (entity, platform) in platform_query.iter() {
-            let mut rng = rand::thread_rng();
             let chance = (PLAYER_MEMORY_SHARDS_SPAWN_RATE_MODIFIER
-                * (1.0 - velocity.value.x / PLAYER_MAX_VELOCITY_X))
+                * (1.0 - scroll.0 / PLAYER_MAX_VELOCITY_X))
                 .into();
 
-            let mut building = commands.entity(entity);
-            building.insert(PreventByte);
+            commands.entity(entity).insert(PreventByte);
 
             if rng.gen_bool(chance) {
                 let mid = (platform.width - 300.0) / (2.0 * WORLD_SPRITE_SCALE.x);
-
                 let x = rng.gen_range(-1.0 * mid..mid);
-                let y = (MAX_FLOATING_Y - MIN_FLOATING_Y) / 2.0 + MIN_FLOATING_Y;
-
-                building.with_children(|parent| {
-                    parent
-                        .spawn(SpriteSheetBundle {
-                            texture: textures.byte.clone(),
-                            atlas: TextureAtlas {
-                                layout: layouts.byte_layout.clone(),
-                                index: 0,
-                            },
-                            transform: Transform {
-                                translation: Vec3::new(x, y, 10.0),
-                                scale: WORLD_SPRITE_SCALE,
-                                ..Default::default()
-                            },
-                            ..Default::default()
-                        })
-                        .insert(Name::new("Byte"))
-                        .insert(Byte::new(x, y, 1.0))
-                        .insert(Collider::cuboid(4.0, 4.0))
-                        .insert(Sensor)
-                        .insert(Animation::auto(
-                            Duration::from_millis(30),
-                            TimerMode::Repeating,
-                            72,
-                        ));
-                });
+
+                Self::place(&mut commands, &textures, &layouts, &mut pool, entity, x);
             }
         }
     }
 
+    /// Floats a byte above `building`, `x` along it in the building's own units.
+    pub fn place(
+        commands: &mut Commands,
+        textures: &TextureAssets,
+        layouts: &SpriteLayouts,
+        pool: &mut Pool<Byte>,
+        building: Entity,
+        x: f32,
+    ) {
+        let y = (MAX_FLOATING_Y - MIN_FLOATING_Y) / 2.0 + MIN_FLOATING_Y;
+        let transform = Transform {
+            translation: Vec3::new(x, y, 10.0),
+            scale: WORLD_SPRITE_SCALE,
+            ..Default::default()
+        };
+
+        if let Some(byte) = pool.take(commands) {
+            commands
+                .entity(byte)
+                .insert((transform, Byte::new(x, y, 1.0)))
+                .remove::<ColliderDisabled>()
+                .set_parent(building);
+            return;
+        }
+
+        let byte = commands
+            .spawn(SpriteSheetBundle {
+                texture: textures.byte.clone(),
+                atlas: TextureAtlas {
+                    layout: layouts.byte_layout.clone(),
+                    index: 0,
+                },
+                transform,
+                ..Default::default()
+            })
+            .insert(Name::new("Byte"))
+            .insert(Byte::new(x, y, 1.0))
+            .insert(Collider::cuboid(4.0, 4.0))
+            .insert(Sensor)
+            .insert(Animation::auto(
+                Duration::from_millis(30),
+                TimerMode::Repeating,
+                72,
+            ))
+            .id();
+        commands.entity(building).add_child(byte);
+    }
+
+    /// Takes a byte out of play and off its building, it can float above another one
+    /// later on.
+    pub fn park(commands: &mut Commands, pool: &mut Pool<Byte>, byte: Entity) {
+        if pool.park(commands, byte) {
+            commands
+                .entity(byte)
+                .remove_parent()
+                .remove::<Byte>()
+                .insert(ColliderDisabled);
+        }
+    }
+
     pub fn float(mut query: Query<(&mut Transform, &mut Byte)>, time: Res<Time>) {
         if query.is_empty() {
             return;
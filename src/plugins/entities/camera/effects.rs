@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use glib::utils::easings;
+use rand::Rng;
+
+use super::*;
+use crate::{
+    plugins::entities::player::{Being, Collected, Landed},
+    Settings,
+};
+
+#[derive(Event, Debug, Clone, Copy)]
+pub enum CameraEffect {
+    /// adds trauma, the shake grows with the square of it
+    Shake(f32),
+    /// zooms the camera in by the given fraction of its scale then eases back
+    Punch(f32),
+}
+
+/// Offsets applied on top of whatever `GameCameraPlugin` eases the camera to, they are
+/// taken off at the start of every frame so the follow logic never sees them.
+#[derive(Component, Reflect, Default, Debug)]
+pub struct CameraEffects {
+    pub trauma: f32,
+    pub punch: f32,
+    pub look_ahead: f32,
+    offset: Vec2,
+    angle: f32,
+    zoom: f32,
+}
+
+pub(super) struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CameraEffect>()
+            .add_systems(
+                Update,
+                (
+                    Self::restore
+                        .before(GameCameraPlugin::follow_player)
+                        .before(GameCameraPlugin::set_focus_on_player),
+                    (
+                        (Self::on_landed, Self::on_collected),
+                        Self::trigger,
+                        Self::decay,
+                        Self::apply,
+                    )
+                        .chain()
+                        .after(CameraSystem::Follow)
+                        .in_set(CameraSystem::Effects)
+                        .run_if(in_state(Focus::Player)),
+                )
+                    .run_if(in_state(GameState::Resumed)),
+            )
+            .add_systems(OnEnter(Being::Dead), Self::on_death)
+            .register_type::<CameraEffects>();
+    }
+}
+
+impl CameraEffectsPlugin {
+    fn restore(mut camera: Query<(&mut Transform, &mut CameraEffects), With<MainCamera>>) {
+        for (mut transform, mut effects) in camera.iter_mut() {
+            transform.translation -= effects.offset.extend(0.0);
+            transform.rotation = Quat::IDENTITY;
+            transform.scale /= 1.0 - effects.zoom;
+
+            effects.offset = Vec2::ZERO;
+            effects.angle = 0.0;
+            effects.zoom = 0.0;
+        }
+    }
+
+    fn trigger(
+        mut camera: Query<&mut CameraEffects, With<MainCamera>>,
+        mut events: EventReader<CameraEffect>,
+        settings: Res<Settings>,
+    ) {
+        if settings.accessibility.reduced_motion {
+            events.clear();
+            return;
+        }
+
+        for event in events.read() {
+            for mut effects in camera.iter_mut() {
+                match *event {
+                    CameraEffect::Shake(trauma) => {
+                        effects.trauma = (effects.trauma + trauma).clamp(0.0, 1.0);
+                    }
+                    CameraEffect::Punch(zoom) => {
+                        effects.punch = (effects.punch + zoom).clamp(0.0, 0.5);
+                    }
+                }
+            }
+        }
+    }
+
+    fn decay(mut camera: Query<&mut CameraEffects, With<MainCamera>>, time: Res<Time>) {
+        for mut effects in camera.iter_mut() {
+            effects.trauma = (effects.trauma - CAMERA_TRAUMA_DECAY * time.delta_seconds()).max(0.0);
+
+            let punch = effects.punch;
+            effects.punch -= easings::expo(punch, CAMERA_PUNCH_DECAY, time.delta_seconds());
+        }
+    }
+
+    fn apply(mut camera: Query<(&mut Transform, &mut CameraEffects), With<MainCamera>>) {
+        let mut rng = rand::thread_rng();
+
+        for (mut transform, mut effects) in camera.iter_mut() {
+            let shake = effects.trauma.powi(2);
+
+            effects.offset = CAMERA_SHAKE_MAX_OFFSET
+                * shake
+                * Vec2::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+            effects.angle = CAMERA_SHAKE_MAX_ANGLE * shake * rng.gen_range(-1.0..=1.0);
+            effects.zoom = effects.punch;
+
+            transform.translation += effects.offset.extend(0.0);
+            transform.rotation = Quat::from_rotation_z(effects.angle);
+            transform.scale *= 1.0 - effects.zoom;
+        }
+    }
+
+    fn on_landed(mut landings: EventReader<Landed>, mut effects: EventWriter<CameraEffect>) {
+        for landing in landings.read() {
+            if landing.impact >= CAMERA_HARD_LANDING_VELOCITY {
+                let trauma = landing.impact / CAMERA_HARD_LANDING_VELOCITY - 0.75;
+                effects.send(CameraEffect::Shake(trauma.min(0.6)));
+                effects.send(CameraEffect::Punch(0.03));
+            }
+        }
+    }
+
+    fn on_collected(mut pickups: EventReader<Collected>, mut effects: EventWriter<CameraEffect>) {
+        for _ in pickups.read() {
+            effects.send(CameraEffect::Punch(0.04));
+        }
+    }
+
+    fn on_death(mut effects: EventWriter<CameraEffect>) {
+        effects.send(CameraEffect::Shake(0.8));
+        effects.send(CameraEffect::Punch(0.1));
+    }
+}
@@ -1,22 +1,46 @@
+#![allow(clippy::type_complexity)]
+
 use bevy::{
     core_pipeline::{bloom::BloomSettings, tonemapping::Tonemapping},
     prelude::*,
+    render::{
+        camera::{ScalingMode, Viewport},
+        view::RenderLayers,
+    },
+    window::PrimaryWindow,
 };
 use bevy_rapier2d::na;
 use glib::utils::easings;
 
 use super::player::*;
-use crate::GameState;
+use crate::{DailyChallenge, DailyModifier, GameState, Settings, Versus};
+
+mod effects;
+
+pub use effects::*;
 
 #[derive(Component)]
 pub struct MainCamera;
 
+/// The camera easing after the runners, anything that has to stay in view goes after
+/// `Follow` and before the `Effects` get applied on top.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CameraSystem {
+    Follow,
+    Effects,
+}
+
+/// Runner a split screen camera keeps in view, cameras without one follow everyone.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CameraTarget(pub usize);
+
 use glib::*;
 
 #[derive(Bundle)]
 pub struct MainCameraBundle {
     camera_2d: Camera2dBundle,
     tag: MainCamera,
+    effects: CameraEffects,
     name: Name,
 }
 
@@ -29,21 +53,31 @@ pub enum Focus {
 
 impl MainCameraBundle {
     pub fn new() -> Self {
-        Self {
-            camera_2d: Camera2dBundle {
-                camera: Camera {
-                    hdr: true,
-                    ..Default::default()
-                },
-                tonemapping: Tonemapping::BlenderFilmic,
-                transform: Transform::from_xyz(
-                    CAMERA_STARTING_POSITIION.x,
-                    CAMERA_STARTING_POSITIION.y,
-                    0.0,
-                ),
+        let mut camera_2d = Camera2dBundle {
+            camera: Camera {
+                hdr: true,
                 ..Default::default()
             },
+            tonemapping: Tonemapping::BlenderFilmic,
+            transform: Transform::from_xyz(
+                CAMERA_STARTING_POSITIION.x,
+                CAMERA_STARTING_POSITIION.y,
+                0.0,
+            ),
+            ..Default::default()
+        };
+
+        // the world is laid out for a fixed virtual resolution, wider or taller windows
+        // simply get to see more of it
+        camera_2d.projection.scaling_mode = ScalingMode::AutoMin {
+            min_width: APP_WINDOW_DESIRED_WITH,
+            min_height: APP_WINDOW_DESIRED_HEIGHT,
+        };
+
+        Self {
+            camera_2d,
             tag: MainCamera,
+            effects: CameraEffects::default(),
             name: Name::new("Main Camera"),
         }
     }
@@ -59,11 +93,17 @@ impl Plugin for GameCameraPlugin {
                 (
                     Self::follow_player
                         .run_if(in_state(Being::Alive))
-                        .run_if(in_state(MovementType::Running)),
-                    Self::set_focus_on_player.run_if(in_state(Focus::Menu)),
+                        .run_if(in_state(MovementType::Running))
+                        .in_set(CameraSystem::Follow),
+                    Self::set_focus_on_player
+                        .run_if(in_state(Focus::Menu))
+                        .in_set(CameraSystem::Follow),
+                    Self::viewports,
                 )
                     .run_if(in_state(GameState::Resumed)),
-            );
+            )
+            .add_systems(OnEnter(GameState::Resumed), Self::split)
+            .add_plugins(CameraEffectsPlugin);
     }
 }
 
@@ -78,8 +118,17 @@ impl GameCameraPlugin {
         mut camera: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
         mut next_foucs: ResMut<NextState<Focus>>,
         time: Res<Time>,
+        settings: Res<Settings>,
     ) {
-        if let Ok(mut transform) = camera.get_single_mut() {
+        // skip the swoop down to the player
+        if settings.accessibility.reduced_motion {
+            next_foucs.set(Focus::Player);
+            return;
+        }
+
+        let mut focused = !camera.is_empty();
+
+        for mut transform in camera.iter_mut() {
             if transform.translation.y >= 0.0 {
                 transform.translation.y -= CAMERA_STARTING_POSITIION.y
                     * (1.0 - time.delta_seconds().powf(10f32.powf(-f32::exp(1.0))));
@@ -89,34 +138,177 @@ impl GameCameraPlugin {
 
                 transform.scale.x = na::clamp(transform.scale.x - ease, 0.3, 1.0);
                 transform.scale.y = na::clamp(transform.scale.y - ease, 0.3, 1.0);
-            } else {
-                next_foucs.set(Focus::Player);
+                focused = false;
             }
         }
+
+        if focused {
+            next_foucs.set(Focus::Player);
+        }
     }
 
+    /// Versus runs with split screen give every runner a camera of their own, stacked
+    /// on top of each other, and draw the ui once over the whole window.
+    fn split(
+        mut commands: Commands,
+        mut cameras: Query<(Entity, &mut Camera, &Transform), With<MainCamera>>,
+        versus: Res<Versus>,
+        settings: Res<Settings>,
+    ) {
+        if !versus.active || !settings.split_screen {
+            return;
+        }
+
+        let Some(transform) = cameras.iter().next().map(|(_, _, transform)| *transform) else {
+            return;
+        };
+
+        // hdr views share one texture and get tonemapped as a whole, each half would be
+        // tonemapped once per camera
+        for (entity, mut camera, _) in cameras.iter_mut() {
+            camera.hdr = false;
+            commands
+                .entity(entity)
+                .remove::<BloomSettings>()
+                .insert(CameraTarget(0));
+        }
+
+        for runner in 1..versus.runners() {
+            let mut bundle = MainCameraBundle::new();
+            bundle.camera_2d.camera.hdr = false;
+            bundle.camera_2d.camera.order = runner as isize;
+            bundle.camera_2d.camera.clear_color = ClearColorConfig::None;
+            bundle.camera_2d.transform = transform;
+            bundle.name = Name::new(format!("Camera {}", runner + 1));
+
+            commands.spawn(bundle).insert(CameraTarget(runner));
+        }
+
+        commands
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    order: versus.runners() as isize,
+                    clear_color: ClearColorConfig::None,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(RenderLayers::none())
+            .insert(IsDefaultUiCamera)
+            .insert(Name::new("Ui Camera"));
+    }
+
+    /// Splits the window in as many rows as there are split screen cameras.
+    fn viewports(
+        window: Query<&Window, With<PrimaryWindow>>,
+        mut cameras: Query<(&mut Camera, &CameraTarget)>,
+        versus: Res<Versus>,
+    ) {
+        let Ok(window) = window.get_single() else {
+            return;
+        };
+
+        let size = UVec2::new(window.physical_width(), window.physical_height());
+        let row = UVec2::new(size.x, size.y / versus.runners() as u32);
+
+        // minimized windows have no room for a viewport
+        if row.x == 0 || row.y == 0 {
+            return;
+        }
+
+        for (mut camera, target) in cameras.iter_mut() {
+            let position = UVec2::new(0, row.y * target.0 as u32);
+
+            if camera.viewport.as_ref().is_some_and(|viewport| {
+                viewport.physical_position == position && viewport.physical_size == row
+            }) {
+                continue;
+            }
+
+            camera.viewport = Some(Viewport {
+                physical_position: position,
+                physical_size: row,
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Follows the runner a camera targets, or the middle of all of them on a shared
+    /// camera, zooming out so that the one trailing behind stays in view.
     fn follow_player(
-        player: Query<&Transform, With<Player>>,
-        mut camera: Query<&mut Transform, (With<MainCamera>, Without<Player>)>,
+        player: Query<(&Transform, &AuxiliaryVelocity, &Runner), With<Player>>,
+        mut camera: Query<
+            (&mut Transform, &mut CameraEffects, Option<&CameraTarget>),
+            (With<MainCamera>, Without<Player>),
+        >,
+        challenge: Res<DailyChallenge>,
         time: Res<Time>,
     ) {
-        for player_transform in player.iter() {
-            for mut camera_transform in camera.iter_mut() {
-                let ease_scale = easings::expo(0.3, 0.05_f32, time.delta_seconds());
+        // the player ends up on the right, seeing less of what's coming
+        let side = match challenge.has(DailyModifier::MirroredCamera) {
+            true => -1.0,
+            false => 1.0,
+        };
+
+        for (mut camera_transform, mut effects, target) in camera.iter_mut() {
+            let followed = player
+                .iter()
+                .filter(|(.., runner)| target.is_none_or(|target| target.0 == runner.0))
+                .map(|(transform, velocity, _)| (transform.translation, velocity.value.x))
+                .collect::<Vec<_>>();
+
+            if followed.is_empty() {
+                continue;
+            }
 
-                camera_transform.scale.x =
-                    na::clamp(camera_transform.scale.x + ease_scale, 0.3, 1.0);
-                camera_transform.scale.y =
-                    na::clamp(camera_transform.scale.y + ease_scale, 0.3, 1.0);
+            let center = followed.iter().map(|(position, _)| *position).sum::<Vec3>()
+                / followed.len() as f32;
+            let velocity = followed
+                .iter()
+                .map(|(_, velocity)| *velocity)
+                .fold(f32::MIN, f32::max);
+            let spread = followed
+                .iter()
+                .map(|(position, _)| (position.x - center.x).abs())
+                .fold(0.0, f32::max);
+
+            let look_ahead = velocity / PLAYER_MAX_VELOCITY_X * CAMERA_LOOK_AHEAD;
+            effects.look_ahead += easings::expo(
+                look_ahead - effects.look_ahead,
+                0.05_f32,
+                time.delta_seconds(),
+            );
+
+            // a lone runner always fits, the zoom stays within the usual range
+            let fit =
+                ((spread + CAMERA_PLAYER_OFFSET.x + effects.look_ahead + VERSUS_CAMERA_MARGIN)
+                    / (APP_WINDOW_DESIRED_WITH / 2.0))
+                    .max(1.0);
+
+            let ease_scale = easings::expo(0.3, 0.05_f32, time.delta_seconds());
+            let zoom = |scale: f32| match scale > fit {
+                true => (scale - ease_scale).max(fit),
+                false => na::clamp(scale + ease_scale, 0.3, fit),
+            };
+
+            camera_transform.scale.x = zoom(camera_transform.scale.x);
+            camera_transform.scale.y = zoom(camera_transform.scale.y);
+
+            let target = center
+                + Vec3::new(
+                    side * (CAMERA_PLAYER_OFFSET.x + effects.look_ahead),
+                    CAMERA_PLAYER_OFFSET.y,
+                    0.0,
+                );
 
-                let target = player_transform.translation
-                    + Vec3::new(CAMERA_PLAYER_OFFSET.x, CAMERA_PLAYER_OFFSET.y, 0.0);
+            let cam = &mut camera_transform.translation;
+            let delta = target - *cam;
 
-                let cam = &mut camera_transform.translation;
-                let delta = target - *cam;
+            cam.x += easings::expo(delta.x, 0.05_f32, time.delta_seconds());
 
-                cam.x += easings::expo(delta.x, 0.05_f32, time.delta_seconds());
-                cam.y += easings::expo(delta.y, 0.05_f32, time.delta_seconds());
+            if delta.y.abs() > CAMERA_DEAD_ZONE_Y {
+                let outside = delta.y - CAMERA_DEAD_ZONE_Y * delta.y.signum();
+                cam.y += easings::expo(outside, 0.05_f32, time.delta_seconds());
             }
         }
     }
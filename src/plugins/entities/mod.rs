@@ -1,6 +1,7 @@
 pub mod background;
 pub mod bytes;
 pub mod camera;
+pub mod particles;
 pub mod player;
 pub mod terrain;
 
@@ -8,6 +9,7 @@ pub mod prelude {
     pub use super::background::*;
     pub use super::bytes::*;
     pub use super::camera::*;
+    pub use super::particles::*;
     pub use super::player::*;
     pub use super::terrain::*;
 }
@@ -0,0 +1,303 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::player::*;
+use super::terrain::Scenery;
+use crate::{GameState, Grounded, SpriteLayouts};
+
+use glib::*;
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticleEffect {
+    Dust,
+    Trail,
+    Shatter,
+}
+
+impl ParticleEffect {
+    fn burst(self, rng: &mut ThreadRng) -> Vec<Particle> {
+        match self {
+            Self::Dust => (0..12)
+                .map(|_| {
+                    Particle::new(
+                        Vec2::new(rng.gen_range(-160.0..160.0), rng.gen_range(10.0..80.0)),
+                        150.0,
+                        rng.gen_range(0.25..0.5),
+                        Color::rgba(0.85, 0.85, 0.85, 0.8),
+                        Color::rgba(0.85, 0.85, 0.85, 0.0),
+                        rng.gen_range(4.0..8.0),
+                    )
+                })
+                .collect(),
+            Self::Trail => (0..2)
+                .map(|_| {
+                    Particle::new(
+                        Vec2::new(rng.gen_range(-60.0..0.0), rng.gen_range(10.0..40.0)),
+                        60.0,
+                        rng.gen_range(0.2..0.35),
+                        Color::rgba(0.8, 0.8, 0.8, 0.5),
+                        Color::rgba(0.8, 0.8, 0.8, 0.0),
+                        4.0,
+                    )
+                })
+                .collect(),
+            Self::Shatter => (0..16)
+                .map(|_| {
+                    let direction = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU));
+                    let color = Color::hsl(rng.gen_range(0.0..360.0), 1.0, 0.6);
+
+                    Particle::new(
+                        direction * rng.gen_range(120.0..320.0),
+                        600.0,
+                        rng.gen_range(0.3..0.6),
+                        color,
+                        color.with_a(0.0),
+                        4.0,
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Component, Reflect, Debug, Clone)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub gravity: f32,
+    pub lifetime: Timer,
+    pub start: Color,
+    pub end: Color,
+    pub size: f32,
+}
+
+impl Particle {
+    pub fn new(
+        velocity: Vec2,
+        gravity: f32,
+        lifetime: f32,
+        start: Color,
+        end: Color,
+        size: f32,
+    ) -> Self {
+        Self {
+            velocity,
+            gravity,
+            lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+            start,
+            end,
+            size,
+        }
+    }
+
+    fn color(&self) -> Color {
+        let ratio = self.lifetime.fraction();
+        let start = Vec4::from(self.start.as_rgba_f32());
+        let end = Vec4::from(self.end.as_rgba_f32());
+
+        Color::rgba_from_array(start.lerp(end, ratio))
+    }
+}
+
+/// Emits its effect every time `timer` finishes for as long as it is `active`.
+#[derive(Component, Reflect, Debug, Clone)]
+pub struct ParticleEmitter {
+    pub effect: ParticleEffect,
+    pub timer: Timer,
+    pub offset: Vec3,
+    pub active: bool,
+}
+
+impl ParticleEmitter {
+    pub fn new(effect: ParticleEffect, rate: Duration, offset: Vec3) -> Self {
+        Self {
+            effect,
+            timer: Timer::new(rate, TimerMode::Repeating),
+            offset,
+            active: false,
+        }
+    }
+}
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (Self::attach_trail, Self::toggle_trail, Self::emit).chain(),
+                (
+                    Self::on_jumped,
+                    Self::on_landed,
+                    Self::on_collected,
+                    Self::on_died,
+                ),
+            )
+                .run_if(in_state(GameState::Resumed)),
+        )
+        .add_systems(Update, Self::simulate)
+        .register_type::<Particle>()
+        .register_type::<ParticleEmitter>();
+    }
+}
+
+impl ParticlesPlugin {
+    pub fn spawn(commands: &mut Commands, position: Vec3, effect: ParticleEffect) {
+        let mut rng = rand::thread_rng();
+
+        for particle in effect.burst(&mut rng) {
+            Self::spawn_particle(commands, position, particle);
+        }
+    }
+
+    fn spawn_particle(commands: &mut Commands, position: Vec3, particle: Particle) {
+        commands
+            .spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: particle.start,
+                    custom_size: Some(Vec2::splat(particle.size)),
+                    ..Default::default()
+                },
+                transform: Transform::from_xyz(position.x, position.y, PARTICLES_Z),
+                ..Default::default()
+            })
+            .insert(particle)
+            .insert(Scenery);
+    }
+
+    fn simulate(
+        mut commands: Commands,
+        mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut particle, mut transform, mut sprite) in particles.iter_mut() {
+            if particle.lifetime.tick(time.delta()).finished() {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+
+            particle.velocity.y -= particle.gravity * time.delta_seconds();
+            transform.translation += particle.velocity.extend(0.0) * time.delta_seconds();
+            sprite.color = particle.color();
+        }
+    }
+
+    fn emit(
+        mut commands: Commands,
+        mut emitters: Query<(&mut ParticleEmitter, &GlobalTransform)>,
+        time: Res<Time>,
+    ) {
+        for (mut emitter, transform) in emitters.iter_mut() {
+            if !emitter.active {
+                continue;
+            }
+
+            if emitter.timer.tick(time.delta()).just_finished() {
+                Self::spawn(
+                    &mut commands,
+                    transform.translation() + emitter.offset,
+                    emitter.effect,
+                );
+            }
+        }
+    }
+
+    fn attach_trail(mut commands: Commands, player: Query<Entity, Added<Player>>) {
+        for entity in player.iter() {
+            commands.entity(entity).insert(ParticleEmitter::new(
+                ParticleEffect::Trail,
+                Duration::from_millis(60),
+                Vec3::new(0.0, -PLAYER_COLLIDER_HEIGHT / 2.0 * PLAYER_SCALE_Y, 0.0),
+            ));
+        }
+    }
+
+    fn toggle_trail(
+        mut player: Query<(Entity, &mut ParticleEmitter, &AuxiliaryVelocity), With<Player>>,
+        grounded: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+    ) {
+        for (entity, mut emitter, velocity) in player.iter_mut() {
+            let Some(grounded) = PlayerGrounded::of(&grounded, entity) else {
+                continue;
+            };
+
+            emitter.active = grounded.value && velocity.value.x >= PARTICLES_TRAIL_VELOCITY;
+        }
+    }
+
+    fn on_jumped(mut commands: Commands, mut jumps: EventReader<Jumped>) {
+        for jump in jumps.read() {
+            Self::spawn(&mut commands, jump.position, ParticleEffect::Dust);
+        }
+    }
+
+    fn on_landed(mut commands: Commands, mut landings: EventReader<Landed>) {
+        for landing in landings.read() {
+            Self::spawn(&mut commands, landing.position, ParticleEffect::Dust);
+        }
+    }
+
+    fn on_collected(mut commands: Commands, mut pickups: EventReader<Collected>) {
+        for pickup in pickups.read() {
+            Self::spawn(&mut commands, pickup.position, ParticleEffect::Shatter);
+        }
+    }
+
+    /// Breaks the frame the player died on into its pixels and lets them drift away.
+    fn on_died(
+        mut commands: Commands,
+        mut deaths: EventReader<Died>,
+        layouts: Res<SpriteLayouts>,
+        images: Res<Assets<Image>>,
+        atlases: Res<Assets<TextureAtlasLayout>>,
+    ) {
+        let Some(layout) = atlases.get(&layouts.player_layout) else {
+            return;
+        };
+
+        let mut rng = rand::thread_rng();
+
+        for death in deaths.read() {
+            let (Some(image), Some(rect)) =
+                (images.get(&death.sheet), layout.textures.get(death.frame))
+            else {
+                continue;
+            };
+
+            let width = image.width() as usize;
+            let center = rect.center();
+
+            for y in (rect.min.y as usize..rect.max.y as usize).step_by(PARTICLES_PIXEL_STEP) {
+                for x in (rect.min.x as usize..rect.max.x as usize).step_by(PARTICLES_PIXEL_STEP) {
+                    let index = (y * width + x) * 4;
+                    let Some(&[r, g, b, a]) = image.data.get(index..index + 4) else {
+                        continue;
+                    };
+
+                    if a == 0 {
+                        continue;
+                    }
+
+                    let offset = Vec2::new(x as f32 - center.x, center.y - y as f32)
+                        * WORLD_SPRITE_SCALE.truncate();
+                    let color = Color::rgba_u8(r, g, b, a);
+
+                    Self::spawn_particle(
+                        &mut commands,
+                        death.position + offset.extend(0.0),
+                        Particle::new(
+                            Vec2::new(rng.gen_range(-40.0..140.0), rng.gen_range(20.0..160.0)),
+                            -40.0,
+                            rng.gen_range(0.6..1.6),
+                            color,
+                            color.with_a(0.0),
+                            PARTICLES_PIXEL_STEP as f32 * WORLD_SPRITE_SCALE.x,
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
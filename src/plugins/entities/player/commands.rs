@@ -0,0 +1,129 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::*;
+use crate::plugins::debug::{arg, CommandResult};
+use crate::plugins::entities::terrain::{Building, Parked, Pool};
+use crate::{Interpolated, PreventByte, Restart, TextureAssets};
+
+impl PlayerPlugin {
+    /// Holds the runners at a speed, `off` lets them speed up again.
+    pub(super) fn speed_command(
+        In(args): In<Vec<String>>,
+        mut speed: ResMut<SpeedOverride>,
+    ) -> CommandResult {
+        if args.first().is_some_and(|arg| arg == "off") {
+            speed.0 = None;
+            return Ok("runners back to their own pace".into());
+        }
+
+        let value: f32 = arg(&args, 0, "speed <velocity|off>")?;
+        speed.hold(value)?;
+
+        Ok(format!("runners held at {value}"))
+    }
+
+    pub(super) fn memory_command(
+        In(args): In<Vec<String>>,
+        mut player: Query<&mut Memory, With<Player>>,
+    ) -> CommandResult {
+        let amount: f32 = arg(&args, 0, "memory <amount>")?;
+
+        for mut memory in player.iter_mut() {
+            memory.set(amount);
+        }
+
+        Ok(format!("memory set to {amount}"))
+    }
+
+    /// Moves the runners `x` along the world, onto a building laid out there for them.
+    /// Generation carries on from it.
+    pub(super) fn tp_command(
+        In(args): In<Vec<String>>,
+        mut commands: Commands,
+        mut player: Query<
+            (
+                &mut Transform,
+                &mut Interpolated,
+                &mut Velocity,
+                &mut Distance,
+            ),
+            With<Player>,
+        >,
+        textures: Res<TextureAssets>,
+        mut pool: ResMut<Pool<Building>>,
+        mut parked: Query<&mut Building, With<Parked>>,
+    ) -> CommandResult {
+        let x: f32 = arg(&args, 0, "tp <x>")?;
+
+        let leader = player
+            .iter()
+            .map(|(transform, ..)| transform.translation.x)
+            .reduce(f32::max)
+            .ok_or("no runner to move")?;
+        let offset = x - leader;
+
+        Building::spawn(
+            &mut commands,
+            &textures,
+            &mut pool,
+            &mut parked,
+            8,
+            x,
+            PLATFORMS_MIN_Y,
+            10.0,
+        )
+        .insert(PreventByte);
+
+        for (mut transform, mut interpolated, mut velocity, mut distance) in player.iter_mut() {
+            transform.translation.x += offset;
+            transform.translation.y = PLATFORMS_MAX_Y;
+            *interpolated = Interpolated::at(transform.translation);
+            velocity.linvel.y = 0.0;
+            distance.value += offset;
+        }
+
+        Ok(format!("runners moved to {x}"))
+    }
+
+    pub(super) fn state_command(
+        In(args): In<Vec<String>>,
+        mut commands: Commands,
+        player: Query<(Entity, &Runner, &Transform, &TextureAtlas, &Handle<Image>), With<Player>>,
+        being: Res<State<Being>>,
+        mut next_being: ResMut<NextState<Being>>,
+        mut died: EventWriter<Died>,
+        mut restart: EventWriter<Restart>,
+    ) -> CommandResult {
+        let usage = "state <Alive|Dead>";
+
+        match (args.first().map(String::as_str), being.get()) {
+            (Some("Dead"), Being::Alive) => {
+                for (entity, runner, transform, atlas, sheet) in player.iter() {
+                    Self::die(
+                        &mut commands,
+                        &mut died,
+                        entity,
+                        Died {
+                            runner: runner.0,
+                            position: transform.translation,
+                            frame: atlas.index,
+                            sheet: sheet.clone(),
+                            cause: DeathCause::Forced,
+                        },
+                    );
+                }
+                next_being.set(Being::Dead);
+                Ok("runners out".into())
+            }
+            (Some("Alive"), Being::Dead) => {
+                restart.send(Restart);
+                Ok("new run started".into())
+            }
+            (Some(state @ ("Alive" | "Dead")), _) => Err(format!("already {state}")),
+            _ => Err(format!("usage: {usage}")),
+        }
+    }
+}
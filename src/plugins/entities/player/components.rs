@@ -1,4 +1,4 @@
-use crate::plugins::debug::*;
+use crate::plugins::{debug::*, game::ground::Grounded};
 use bevy::prelude::*;
 
 #[derive(Component, Reflect, Default)]
@@ -12,19 +12,128 @@ pub struct Jump {
 #[derive(Component, Default)]
 pub(crate) struct Player;
 
+/// Which of the local players a runner is.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Runner(pub usize);
+
+/// The runner the single player parts of the game follow, the hud, achievements and
+/// narrative among others.
+#[derive(Component, Default)]
+pub(crate) struct Primary;
+
+/// Key and gamepad a runner jumps with.
+#[derive(Component, Reflect, Debug, Clone)]
+pub struct Controls {
+    pub jump: KeyCode,
+    /// index among the connected gamepads
+    pub gamepad: usize,
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        Self::of(0)
+    }
+}
+
+impl Controls {
+    pub fn of(runner: usize) -> Self {
+        Self {
+            jump: match runner {
+                0 => KeyCode::Space,
+                _ => KeyCode::ArrowUp,
+            },
+            gamepad: runner,
+        }
+    }
+
+    fn button(&self, gamepads: &Gamepads) -> Option<GamepadButton> {
+        gamepads
+            .iter()
+            .nth(self.gamepad)
+            .map(|gamepad| GamepadButton::new(gamepad, GamepadButtonType::South))
+    }
+
+    pub fn just_pressed(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        gamepads: &Gamepads,
+        buttons: &ButtonInput<GamepadButton>,
+    ) -> bool {
+        keys.just_pressed(self.jump)
+            || self
+                .button(gamepads)
+                .is_some_and(|button| buttons.just_pressed(button))
+    }
+
+    pub fn just_released(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        gamepads: &Gamepads,
+        buttons: &ButtonInput<GamepadButton>,
+    ) -> bool {
+        keys.just_released(self.jump)
+            || self
+                .button(gamepads)
+                .is_some_and(|button| buttons.just_released(button))
+    }
+}
+
+/// Jump presses and releases waiting for the next gameplay tick, whatever drives a
+/// runner goes through it.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+pub struct JumpInput {
+    pub pressed: bool,
+    pub released: bool,
+}
+
 #[derive(Component)]
 pub struct PlayerGrounded;
 
+impl PlayerGrounded {
+    /// Ground check of `player`, spawned as one of its children.
+    pub fn of<'a>(
+        checks: &'a Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        player: Entity,
+    ) -> Option<&'a Grounded> {
+        checks
+            .iter()
+            .find_map(|(grounded, parent)| (parent.get() == player).then_some(grounded))
+    }
+}
+
 #[derive(Component, Reflect, Default)]
 pub(crate) struct AuxiliaryVelocity {
     pub value: Vec2,
 }
 
+/// Speed the debug tools hold every runner at, they stop speeding up until it's
+/// cleared.
+#[derive(Resource, Debug, Default)]
+pub struct SpeedOverride(pub Option<f32>);
+
+impl SpeedOverride {
+    pub fn hold(&mut self, speed: f32) -> Result<(), String> {
+        // `f32` parses "nan" and "inf" just fine
+        if !speed.is_finite() || speed < 0.0 {
+            return Err(format!("{speed} is not a speed"));
+        }
+
+        self.0 = Some(speed);
+        Ok(())
+    }
+}
+
 #[derive(Component, Reflect, Default)]
 pub struct AuxiliaryAcceleration {
     pub value: Vec2,
 }
 
+#[derive(Component, Reflect, Default)]
+pub struct Landing {
+    pub airborne: bool,
+    pub impact: f32,
+}
+
 #[derive(Component, Default, Reflect)]
 pub struct WalkingTimer(pub Timer);
 
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+/// Sent when the jump impulse is applied, `position` being the player's feet.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Jumped {
+    pub player: Entity,
+    pub position: Vec3,
+}
+
+/// Sent by the jump assist, the player jumps as if the jump key got pressed and held.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AssistedJump {
+    pub player: Entity,
+}
+
+/// Sent once the player touches a ground after being airborne, `impact` being the
+/// highest falling speed reached during the fall.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Landed {
+    pub player: Entity,
+    pub impact: f32,
+    pub position: Vec3,
+    pub ground: Option<Entity>,
+}
+
+/// Sent whenever the player picks up a byte.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Collected {
+    pub player: Entity,
+    pub position: Vec3,
+}
+
+/// What took a runner out of the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathCause {
+    Fell,
+    OutOfMemory,
+    /// too far behind the leader in versus
+    LeftBehind,
+    /// the debug tools ended the run
+    Forced,
+}
+
+impl DeathCause {
+    /// Name the run records use.
+    pub fn key(&self) -> &'static str {
+        match self {
+            DeathCause::Fell => "fell",
+            DeathCause::OutOfMemory => "out-of-memory",
+            DeathCause::LeftBehind => "left-behind",
+            DeathCause::Forced => "forced",
+        }
+    }
+}
+
+/// Sent right before the player gets despawned, `frame` is the atlas index it was
+/// showing at that moment and `sheet` the skin it was wearing.
+#[derive(Event, Debug, Clone)]
+pub struct Died {
+    /// the entity is gone by the time the event is read, so the runner is sent instead
+    pub runner: usize,
+    pub position: Vec3,
+    pub frame: usize,
+    pub sheet: Handle<Image>,
+    pub cause: DeathCause,
+}
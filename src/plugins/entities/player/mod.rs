@@ -1,21 +1,22 @@
 #![allow(clippy::all)]
 
-use std::time::Duration;
-
 use glib::*;
 
+mod commands;
 mod components;
+mod events;
 mod plugins;
 mod states;
 
 pub use components::*;
+pub use events::*;
 pub use plugins::*;
 pub use states::*;
 
-use bevy::prelude::*;
-use bevy_rapier2d::na;
+use bevy::{input::InputSystem, prelude::*};
 use bevy_rapier2d::prelude::*;
 
+use crate::plugins::debug::ConsoleApp;
 use crate::plugins::game::ground::*;
 use crate::*;
 
@@ -29,49 +30,116 @@ impl Plugin for PlayerPlugin {
             .init_state::<Being>();
         // systems
         app.add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
-            .add_systems(OnEnter(GameState::Resumed), Self::setup_dialog_text)
             .add_systems(
                 Update,
                 (
                     Self::set_animations,
-                    Self::update,
                     Self::control_animations.run_if(in_state(GameAssetsState::Loaded)),
-                    (Self::movement, Self::jump, Self::collect)
-                        .run_if(in_state(MovementType::Running))
-                        .run_if(in_state(Being::Alive)),
                 )
                     .run_if(in_state(GameState::Resumed)),
-            )
-            .add_systems(Update, Self::being.run_if(in_state(GameState::Resumed)));
+            );
+        Self::simulation(app);
         // types
         app.register_type::<PlayerAnimation>()
             .register_type::<PlayerAnimationController>()
             .register_type::<AuxiliaryVelocity>()
             .register_type::<AuxiliaryAcceleration>()
             .register_type::<WalkingTimer>()
-            .register_type::<Jump>();
+            .register_type::<Jump>()
+            .register_type::<Landing>()
+            .register_type::<Runner>()
+            .register_type::<Controls>()
+            .register_type::<JumpInput>();
+        // console
+        app.console_command(
+            "speed",
+            "speed <velocity|off>",
+            &["off"],
+            Self::speed_command,
+        )
+        .console_command("memory", "memory <amount>", &[], Self::memory_command)
+        .console_command("tp", "tp <x>", &[], Self::tp_command)
+        .console_command(
+            "state",
+            "state <Alive|Dead>",
+            &["Alive", "Dead"],
+            Self::state_command,
+        );
         // plugins
         app.add_plugins(PlayerMemoryPlugin);
         app.add_plugins(PlayerScorePlugin);
+        app.add_plugins(PlayerSkinsPlugin);
     }
 }
 
 impl PlayerPlugin {
+    /// Input, movement, jumping and dying, all a run needs without anything drawn.
+    /// Headless harnesses add just these.
+    pub(crate) fn simulation(app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            Self::read_controls
+                .after(InputSystem)
+                .run_if(in_state(GameState::Resumed))
+                .run_if(in_state(MovementType::Running))
+                .run_if(in_state(Being::Alive)),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                Self::update,
+                Self::land,
+                Self::being,
+                Self::gravity,
+                (Self::movement, Self::jump, Self::collect)
+                    .chain()
+                    .run_if(in_state(MovementType::Running))
+                    .run_if(in_state(Being::Alive)),
+                Self::pace.run_if(in_state(Being::Alive)),
+            )
+                .chain()
+                .in_set(GameplaySet)
+                .run_if(in_state(GameState::Resumed)),
+        )
+        .add_systems(OnEnter(Being::Dead), Self::halt)
+        .init_resource::<SpeedOverride>()
+        .add_event::<Jumped>()
+        .add_event::<AssistedJump>()
+        .add_event::<Landed>()
+        .add_event::<Collected>()
+        .add_event::<Died>();
+    }
+
     pub(crate) fn setup(
         mut commands: Commands,
         textures: Res<TextureAssets>,
         layouts: Res<SpriteLayouts>,
+        versus: Res<Versus>,
     ) {
-        commands
-            .spawn(PlayerBundle::new(
-                textures.player.clone(),
-                layouts.player_layout.clone(),
-                0,
-            ))
-            .insert(MemoryTimer(Timer::new(
-                Duration::from_secs(1),
-                TimerMode::Repeating,
-            )))
+        for runner in 0..versus.runners() {
+            Self::spawn(&mut commands, &textures, &layouts, runner);
+        }
+    }
+
+    /// Spawns the `runner`th player, the first one being the `Primary` runner.
+    pub(crate) fn spawn(
+        commands: &mut Commands,
+        textures: &TextureAssets,
+        layouts: &SpriteLayouts,
+        runner: usize,
+    ) -> Entity {
+        let mut player = commands.spawn(PlayerBundle::new(
+            textures.player.clone(),
+            layouts.player_layout.clone(),
+            0,
+            runner,
+        ));
+
+        if runner == 0 {
+            player.insert(Primary);
+        }
+
+        player
             .with_children(|commands| {
                 commands
                     .spawn(Collider::cuboid(PLAYER_COLLIDER_WIDTH / 2.0, 2.0))
@@ -85,7 +153,8 @@ impl PlayerPlugin {
                     .insert(Name::new("Ground Check"))
                     .insert(Grounded::new(false))
                     .insert(PlayerGrounded);
-            });
+            })
+            .id()
     }
 
     fn update(
@@ -93,44 +162,122 @@ impl PlayerPlugin {
         mut next_controlable: ResMut<NextState<MovementType>>,
         time: Res<Time>,
     ) {
-        let Ok((mut timer, mut velocity)) = query.get_single_mut() else {
-            return;
-        };
+        for (mut timer, mut velocity) in query.iter_mut() {
+            let tick = timer.0.tick(time.delta());
+            let velocity_x = &mut velocity.value.x;
 
-        let tick = timer.0.tick(time.delta());
-        let velocity_x = &mut velocity.value.x;
+            if tick.just_finished() {
+                next_controlable.set(MovementType::Running);
+            }
 
-        if tick.just_finished() {
-            next_controlable.set(MovementType::Running);
+            if tick.finished() && *velocity_x < PLAYER_VELOCITY_BUMP {
+                *velocity_x +=
+                    (PLAYER_VELOCITY_BUMP - *velocity_x) * (1.0 - time.delta_seconds().powi(12));
+            }
         }
+    }
 
-        if tick.finished() && *velocity_x < PLAYER_VELOCITY_BUMP {
-            *velocity_x +=
-                (PLAYER_VELOCITY_BUMP - *velocity_x) * (1.0 - time.delta_seconds().powi(12));
+    fn land(
+        mut player: Query<(&Transform, &Velocity, &mut Landing), With<Player>>,
+        children: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        mut landed: EventWriter<Landed>,
+    ) {
+        for (grounded, parent) in children.iter() {
+            let Ok((transform, velocity, mut landing)) = player.get_mut(parent.get()) else {
+                continue;
+            };
+
+            if grounded.value {
+                if landing.airborne {
+                    landed.send(Landed {
+                        player: parent.get(),
+                        impact: landing.impact,
+                        position: Self::feet(transform),
+                        ground: grounded.ground,
+                    });
+                }
+
+                landing.airborne = false;
+                landing.impact = 0.0;
+            } else {
+                landing.airborne = true;
+                landing.impact = landing.impact.max(-velocity.linvel.y);
+            }
         }
     }
 
+    /// Runners die falling off, running out of memory or, in versus, falling too far
+    /// behind the leader. The run goes on until the last one is out.
     fn being(
         mut commands: Commands,
         mut next_being: ResMut<NextState<Being>>,
-        player: Query<(Entity, &Transform, &Memory), With<Player>>,
+        player: Query<
+            (
+                Entity,
+                &Runner,
+                &Transform,
+                &TextureAtlas,
+                &Handle<Image>,
+                &Memory,
+            ),
+            With<Player>,
+        >,
+        mut died: EventWriter<Died>,
+        scroll: Res<ScrollSpeed>,
     ) {
-        let Ok((entity, transform, memory)) = player.get_single() else {
-            return;
-        };
+        let leader = player
+            .iter()
+            .map(|(_, _, transform, ..)| transform.translation.x)
+            .fold(f32::MIN, f32::max);
+        // as far as the leader gets in `VERSUS_MAX_LAG`, runners all go the same speed
+        // and only drift apart while one of them is held up
+        let gap = (VERSUS_MAX_LAG * PLAYER_VELOCITY_SCALE * scroll.0).max(VERSUS_MIN_GAP);
+        let mut standing = player.iter().len();
+
+        for (entity, runner, transform, atlas, sheet, memory) in player.iter() {
+            let cause = if transform.translation.y < PLATFORMS_MIN_Y {
+                DeathCause::Fell
+            } else if memory.is_empty() {
+                DeathCause::OutOfMemory
+            } else if leader - transform.translation.x > gap {
+                DeathCause::LeftBehind
+            } else {
+                continue;
+            };
+
+            standing -= 1;
+            Self::die(
+                &mut commands,
+                &mut died,
+                entity,
+                Died {
+                    runner: runner.0,
+                    position: transform.translation,
+                    frame: atlas.index,
+                    sheet: sheet.clone(),
+                    cause,
+                },
+            );
 
-        let mut die = || {
-            next_being.set(Being::Dead);
-            commands.entity(entity).despawn_recursive();
-        };
+            if standing == 0 {
+                next_being.set(Being::Dead);
+            }
+        }
+    }
 
-        if transform.translation.y < PLATFORMS_MIN_Y || memory.value == 0.0 {
-            die();
+    /// Takes a runner out of the run, the run itself ends once nobody is left.
+    pub(crate) fn die(
+        commands: &mut Commands,
+        died: &mut EventWriter<Died>,
+        entity: Entity,
+        death: Died,
+    ) {
+        died.send(death);
+        commands.entity(entity).despawn_recursive();
 
-            #[cfg(all(target_os = "windows", feature = "bsod"))]
-            {
-                glib::bsod::bsod();
-            }
+        #[cfg(all(target_os = "windows", feature = "bsod"))]
+        {
+            glib::bsod::bsod();
         }
     }
 
@@ -139,23 +286,52 @@ impl PlayerPlugin {
             (
                 &mut AuxiliaryVelocity,
                 &mut AuxiliaryAcceleration,
-                &mut Score,
+                &mut Distance,
             ),
             With<Player>,
         >,
+        speed: Res<SpeedOverride>,
         time: Res<Time>,
     ) {
-        if query.is_empty() {
-            return;
+        for (mut velocity, mut acceleration, mut distance) in query.iter_mut() {
+            match speed.0 {
+                Some(speed) => velocity.value.x = speed,
+                None => {
+                    velocity.value.x += acceleration.value.x * time.delta_seconds();
+                    acceleration.value.x = PLAYER_INIT_ACCELERATION_X
+                        * (1.0 - velocity.value.x / PLAYER_MAX_VELOCITY_X);
+                }
+            }
+
+            distance.value += PLAYER_VELOCITY_SCALE * velocity.value.x * time.delta_seconds();
         }
+    }
 
-        let (mut velocity, mut acceleration, mut score) = query.single_mut();
+    /// Runs every runner through the world at twice its velocity, the world goes by at
+    /// the fastest one's pace.
+    fn pace(
+        mut runners: Query<(&mut Velocity, &AuxiliaryVelocity), With<Player>>,
+        mut scroll: ResMut<ScrollSpeed>,
+    ) {
+        // the world stops once everyone is out
+        scroll.0 = runners
+            .iter()
+            .map(|(_, velocity)| velocity.value.x)
+            .reduce(f32::max)
+            .unwrap_or_default();
+
+        for (mut velocity, auxiliary) in runners.iter_mut() {
+            velocity.linvel.x = PLAYER_VELOCITY_SCALE * auxiliary.value.x;
+        }
+    }
 
-        velocity.value.x += acceleration.value.x * time.delta_seconds();
-        acceleration.value.x =
-            PLAYER_INIT_ACCELERATION_X * (1.0 - velocity.value.x / PLAYER_MAX_VELOCITY_X);
+    /// Whoever is left standing once the run is over stops where they are.
+    fn halt(mut runners: Query<&mut Velocity, With<Player>>, mut scroll: ResMut<ScrollSpeed>) {
+        scroll.0 = 0.0;
 
-        score.value += (velocity.value.x / 100f32) * time.delta_seconds();
+        for mut velocity in runners.iter_mut() {
+            velocity.linvel.x = 0.0;
+        }
     }
 
     fn control_animations(
@@ -166,134 +342,179 @@ impl PlayerPlugin {
             Option<&mut Animation>,
         )>,
     ) {
-        if query.is_empty() {
-            return;
-        }
-
-        let (entity, controller, animation) = query.single_mut();
-
-        match animation {
-            Some(mut animation) => {
-                let new_animation = controller.curr_animation.animation();
-                if *animation != new_animation {
-                    *animation = new_animation;
+        for (entity, controller, animation) in query.iter_mut() {
+            match animation {
+                Some(mut animation) => {
+                    let new_animation = controller.curr_animation.animation();
+                    if *animation != new_animation {
+                        *animation = new_animation;
+                    }
+                }
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(controller.curr_animation.animation());
                 }
-            }
-            None => {
-                commands
-                    .entity(entity)
-                    .insert(controller.curr_animation.animation());
             }
         }
     }
 
     fn set_animations(
-        mut player: Query<
-            (
-                &Velocity,
-                &AuxiliaryVelocity,
-                &mut GravityScale,
-                &mut PlayerAnimationController,
-            ),
-            With<Animation>,
-        >,
-        player_children: Query<&Grounded, With<PlayerGrounded>>,
+        mut player: Query<(
+            &Velocity,
+            &AuxiliaryVelocity,
+            &mut PlayerAnimationController,
+        )>,
+        player_children: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
     ) {
-        if player.is_empty() {
-            return;
+        for (grounded, parent) in player_children.iter() {
+            let Ok((velocity, aux_velocity, mut controller)) = player.get_mut(parent.get()) else {
+                continue;
+            };
+
+            if grounded.value {
+                if aux_velocity.value.x == 0.0 {
+                    controller.curr_animation = PlayerAnimation::Idle;
+                } else if aux_velocity.value.x != 0.0
+                    && aux_velocity.value.x < PLAYER_INIT_VELOCITY_X + PLAYER_VELOCITY_BUMP
+                {
+                    controller.curr_animation = PlayerAnimation::Walking;
+                } else {
+                    controller.curr_animation = PlayerAnimation::Running;
+                }
+            } else if velocity.linvel.y < -0.01 {
+                controller.curr_animation = PlayerAnimation::Falling;
+            } else if velocity.linvel.y > 0.01 {
+                controller.curr_animation = PlayerAnimation::Rising;
+            }
         }
+    }
 
-        let (velocity, aux_velocity, mut gravity, mut controller) = player.single_mut();
-        let grounded = player_children.single();
+    /// Airborne runners go up at the rise gravity and come down at the fall gravity.
+    /// Ticks right before the jump so a descent is the same at any frame rate, and a
+    /// released jump still gets its tick of fall gravity.
+    fn gravity(
+        mut player: Query<(&Velocity, &mut GravityScale), With<Player>>,
+        player_children: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+    ) {
+        for (grounded, parent) in player_children.iter() {
+            let Ok((velocity, mut gravity)) = player.get_mut(parent.get()) else {
+                continue;
+            };
 
-        if grounded.value {
-            if aux_velocity.value.x == 0.0 {
-                controller.curr_animation = PlayerAnimation::Idle;
-            } else if aux_velocity.value.x != 0.0
-                && aux_velocity.value.x < PLAYER_INIT_VELOCITY_X + PLAYER_VELOCITY_BUMP
-            {
-                controller.curr_animation = PlayerAnimation::Walking;
-            } else {
-                controller.curr_animation = PlayerAnimation::Running;
+            if grounded.value {
+                continue;
+            }
+
+            if velocity.linvel.y < -0.01 {
+                *gravity = GravityScale(PLAYER_FALL_GRAVITY);
+            } else if velocity.linvel.y > 0.01 {
+                *gravity = GravityScale(PLAYER_RISE_GRAVITY);
             }
-        } else if velocity.linvel.y < -0.01 {
-            controller.curr_animation = PlayerAnimation::Falling;
-            *gravity = GravityScale(PLAYER_FALL_GRAVITY);
-        } else if velocity.linvel.y > 0.01 {
-            controller.curr_animation = PlayerAnimation::Rising;
-            *gravity = GravityScale(PLAYER_RISE_GRAVITY);
         }
     }
 
-    fn jump(
+    /// Holds on to presses and releases until the next tick, frames can come faster
+    /// than ticks and a tap must not get lost in between.
+    pub(crate) fn read_controls(
+        mut player: Query<(&Controls, &mut JumpInput), With<Player>>,
+        keys: Res<ButtonInput<KeyCode>>,
+        gamepads: Res<Gamepads>,
+        buttons: Res<ButtonInput<GamepadButton>>,
+    ) {
+        for (controls, mut input) in player.iter_mut() {
+            input.pressed |= controls.just_pressed(&keys, &gamepads, &buttons);
+            input.released |= controls.just_released(&keys, &gamepads, &buttons);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn jump(
         mut commands: Commands,
         mut player: Query<
             (
                 Entity,
+                &Transform,
                 &ReadMassProperties,
                 &mut Velocity,
-                &AuxiliaryVelocity,
+                &mut JumpInput,
                 &mut Jump,
                 &mut GravityScale,
             ),
             With<Player>,
         >,
-        children: Query<&Grounded, With<PlayerGrounded>>,
-        input: Res<ButtonInput<KeyCode>>,
+        children: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        mut jumped: EventWriter<Jumped>,
+        mut assisted: EventReader<AssistedJump>,
         time: Res<Time>,
         rules: Res<RapierConfiguration>,
     ) {
-        if children.is_empty() {
-            return;
-        }
-
-        let (entity, mass, mut velocity, _, mut jump, mut gravity) = player.single_mut();
-        let grounded = children.single().value;
-
-        if grounded {
-            jump.coyote = PLAYER_COYOTE_JUMP_TIME;
-            *gravity = GravityScale(1.0);
-        } else {
-            jump.coyote -= time.delta_seconds();
-        }
-
-        if input.just_pressed(KeyCode::Space) {
-            jump.coyote = 0.0;
-            jump.press = 0.0;
-            jump.buffering = PLAYER_JUMP_BUFFERING_TIME;
-        } else {
-            jump.buffering -= time.delta_seconds();
-        }
-
-        let jump_magnitude = mass.get().mass
-            * (PLAYER_JUMP_HEIGHT * -2.0 * rules.gravity.y / PLAYER_FALL_GRAVITY).sqrt();
-        if jump.buffering > 0.0 && jump.coyote > 0.0 {
-            commands.entity(entity).insert(ExternalImpulse {
-                impulse: Vec2::new(0.0, jump_magnitude),
-                torque_impulse: 0.0,
-            });
+        let assisted = assisted
+            .read()
+            .map(|event| event.player)
+            .collect::<Vec<_>>();
+
+        for (entity, transform, mass, mut velocity, mut input, mut jump, mut gravity) in
+            player.iter_mut()
+        {
+            let JumpInput { pressed, released } = std::mem::take(&mut *input);
+
+            let Some(grounded) = PlayerGrounded::of(&children, entity) else {
+                continue;
+            };
+
+            if grounded.value {
+                jump.coyote = PLAYER_COYOTE_JUMP_TIME;
+                *gravity = GravityScale(1.0);
+            } else {
+                jump.coyote -= time.delta_seconds();
+            }
 
-            jump.buffering = 0.0;
-            jump.rising = true;
-        }
+            if pressed || assisted.contains(&entity) {
+                jump.press = 0.0;
+                jump.buffering = PLAYER_JUMP_BUFFERING_TIME;
+            } else {
+                jump.buffering -= time.delta_seconds();
+            }
 
-        if jump.rising {
-            jump.press += time.delta_seconds();
+            let jump_magnitude = mass.get().mass
+                * (PLAYER_JUMP_HEIGHT * -2.0 * rules.gravity.y / PLAYER_FALL_GRAVITY).sqrt();
+            if jump.buffering > 0.0 && jump.coyote > 0.0 {
+                commands.entity(entity).insert(ExternalImpulse {
+                    impulse: Vec2::new(0.0, jump_magnitude),
+                    torque_impulse: 0.0,
+                });
+
+                jump.buffering = 0.0;
+                // spent by the jump rather than by the press, a press right after
+                // running off a ledge still gets its coyote jump
+                jump.coyote = 0.0;
+                jump.rising = true;
+
+                jumped.send(Jumped {
+                    player: entity,
+                    position: Self::feet(transform),
+                });
+            }
 
-            if input.just_released(KeyCode::Space) {
-                if jump.press < PLAYER_JUMP_WINDOW {
-                    jump.press = 0.0;
-                    commands.entity(entity).insert(ExternalImpulse {
-                        impulse: Vec2::new(0.0, -1.0 * f32::exp(-0.9) * jump_magnitude),
-                        torque_impulse: 0.0,
-                    });
+            if jump.rising {
+                jump.press += time.delta_seconds();
+
+                if released {
+                    if jump.press < PLAYER_JUMP_WINDOW {
+                        jump.press = 0.0;
+                        commands.entity(entity).insert(ExternalImpulse {
+                            impulse: Vec2::new(0.0, -1.0 * f32::exp(-0.9) * jump_magnitude),
+                            torque_impulse: 0.0,
+                        });
+                    }
+                    *gravity = GravityScale(PLAYER_FALL_GRAVITY);
                 }
-                *gravity = GravityScale(PLAYER_FALL_GRAVITY);
-            }
 
-            if velocity.linvel.y < 0.0 {
-                velocity.linvel.y -= velocity.linvel.y * time.delta_seconds() * 2.0;
-                jump.rising = false;
+                if velocity.linvel.y < 0.0 {
+                    velocity.linvel.y -= velocity.linvel.y * time.delta_seconds() * 2.0;
+                    jump.rising = false;
+                }
             }
         }
     }
@@ -306,57 +527,50 @@ impl PlayerPlugin {
             return;
         }
 
-        let mut velocity = query.single_mut();
-        *velocity = AuxiliaryVelocity {
-            value: Vec2::new(PLAYER_RESPAWN_VELOCITY, 0.0),
-        };
+        for mut velocity in query.iter_mut() {
+            *velocity = AuxiliaryVelocity {
+                value: Vec2::new(PLAYER_RESPAWN_VELOCITY, 0.0),
+            };
+        }
 
         next_movement.set(MovementType::Running);
     }
 
-    pub fn setup_dialog_text(mut commands: Commands, fonts: Res<FontsAssets>) {
-        commands
-            .spawn(
-                TextBundle::from_section(
-                    "",
-                    TextStyle {
-                        font: fonts.vcr.clone(),
-                        font_size: 34.0,
-                        color: Color::WHITE,
-                    },
-                )
-                .with_style(Style {
-                    justify_self: JustifySelf::Center,
-                    align_self: AlignSelf::End,
-                    margin: UiRect::bottom(Val::Px(50.0)),
-                    ..Default::default()
-                }),
-            )
-            .insert(Name::new("Self Dialog"))
-            .insert(Dialog::new(
-                DIALOG_LINES
-                    .iter()
-                    .map(|(content, duration)| Line::new(content, *duration))
-                    .collect::<Vec<Line>>(),
-            ));
+    fn feet(transform: &Transform) -> Vec3 {
+        transform.translation - Vec3::Y * PLAYER_COLLIDER_HEIGHT / 2.0 * transform.scale.y
     }
 
     pub fn collect(
         mut commands: Commands,
         mut player: Query<(Entity, &mut Memory), With<Player>>,
-        bytes: Query<Entity, With<Byte>>,
+        bytes: Query<(Entity, &GlobalTransform), With<Byte>>,
+        mut collected: EventWriter<Collected>,
+        mut pool: ResMut<Pool<Byte>>,
         ctx: Res<RapierContext>,
     ) {
         if player.is_empty() || bytes.is_empty() {
             return;
         }
 
-        let (player, mut memory) = player.single_mut();
+        let mut taken = Vec::new();
 
-        for byte in bytes.iter() {
-            if ctx.intersection_pair(byte, player) == Some(true) {
-                commands.entity(byte).despawn_recursive();
-                memory.value = na::clamp(memory.value + PLAYER_MEMORY_REGEN_RATE, 0.0, 100.0);
+        for (player, mut memory) in player.iter_mut() {
+            for (byte, transform) in bytes.iter() {
+                if taken.contains(&byte) || ctx.intersection_pair(byte, player) != Some(true) {
+                    continue;
+                }
+
+                taken.push(byte);
+                BytesPlugin::park(&mut commands, &mut pool, byte);
+                memory.add(PLAYER_MEMORY_REGEN_RATE);
+                memory.modify(
+                    MeterModifier::new("modifier-cache", PLAYER_MEMORY_CACHE_DURATION)
+                        .drain(PLAYER_MEMORY_CACHE_DRAIN),
+                );
+                collected.send(Collected {
+                    player,
+                    position: transform.translation(),
+                });
             }
         }
     }
@@ -378,23 +592,48 @@ struct PlayerBundle {
     pub auxiliary_velocity: AuxiliaryVelocity,
     pub auxiliary_acceleration: AuxiliaryAcceleration,
     pub gravity_scale: GravityScale,
-    pub responsive: Responsive,
     pub tag: Player,
+    pub runner: Runner,
+    pub controls: Controls,
+    pub input: JumpInput,
+    pub interpolated: Interpolated,
+    pub collision_groups: CollisionGroups,
+    pub friction: Friction,
     pub walking_timer: WalkingTimer,
+    pub landing: Landing,
     // stats
     pub memory: Memory,
     pub score: Score,
+    pub distance: Distance,
+    pub combo: Combo,
+    pub flight: Flight,
 }
 
 impl PlayerBundle {
-    fn new(texture: Handle<Image>, layout: Handle<TextureAtlasLayout>, index: usize) -> Self {
+    fn new(
+        texture: Handle<Image>,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+        runner: usize,
+    ) -> Self {
+        let name = match runner {
+            0 => Name::new("Player"),
+            _ => Name::new(format!("Player {}", runner + 1)),
+        };
+
+        let translation = Vec3::new(
+            PLAYER_SPAWN_X - runner as f32 * VERSUS_SPAWN_SPACING,
+            40.0,
+            10.0,
+        );
+
         Self {
-            name: Name::new("Player"),
+            name,
             spritesheet: SpriteSheetBundle {
                 texture,
                 atlas: TextureAtlas { layout, index },
                 transform: Transform {
-                    translation: Vec3::new(-40.0, 40.0, 10.0),
+                    translation,
                     scale: Vec3::new(PLAYER_SCALE_X, PLAYER_SCALE_Y, 0.0),
                     ..Default::default()
                 },
@@ -407,16 +646,25 @@ impl PlayerBundle {
             sleeping: Sleeping::disabled(),
             gravity_scale: GravityScale(1.0),
             mass_properties: AdditionalMassProperties::Mass(PLAYER_MASS),
+            // the horizontal speed is driven by `AuxiliaryVelocity`, rubbing against the
+            // roofs would only eat into it
+            friction: Friction {
+                coefficient: 0.0,
+                combine_rule: CoefficientCombineRule::Min,
+            },
+            // runners go through each other
+            collision_groups: CollisionGroups::new(Group::GROUP_2, Group::ALL ^ Group::GROUP_2),
             read_mass_properties: ReadMassProperties::default(),
             // virtual movement
             auxiliary_velocity: AuxiliaryVelocity {
                 value: Vec2::new(PLAYER_INIT_VELOCITY_X, 0.0),
             },
             walking_timer: WalkingTimer(Timer::new(PLAYER_WALKING_TIMER, TimerMode::Once)),
+            runner: Runner(runner),
+            controls: Controls::of(runner),
+            interpolated: Interpolated::at(translation),
             // stats
-            memory: Memory {
-                value: PLAYER_MAX_MEMORY,
-            },
+            memory: PlayerMemoryPlugin::memory(),
             ..Default::default()
         }
     }
@@ -1,129 +1,62 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
-use bevy_rapier2d::na;
-use bevy_tweening::*;
 
 use super::super::*;
 
-#[derive(Debug, Default, Component, Reflect)]
-pub struct Memory {
-    pub value: f32,
-}
-
-#[derive(Debug, Default, Component, Reflect)]
-pub struct SanityBar;
+/// Tells the player's memory apart from any other meter.
+#[derive(Reflect, Debug, Default, Clone, Copy)]
+pub struct MemoryKind;
 
-#[derive(Debug, Default, Component, Reflect)]
-pub struct MemoryTimer(pub Timer);
+pub type Memory = Meter<MemoryKind>;
 
 pub(in super::super) struct PlayerMemoryPlugin;
 
 impl Plugin for PlayerMemoryPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(MovementType::Running), Self::setup)
-            .add_systems(FixedUpdate, Self::update)
+        app.add_plugins(GameMeterPlugin::<MemoryKind>::default())
             .add_systems(
-                Update,
-                Self::drain
+                FixedUpdate,
+                (
+                    Self::scale_drain,
+                    Self::on_landed,
+                    GameMeterPlugin::<MemoryKind>::tick,
+                )
+                    .chain()
+                    .in_set(GameplaySet)
                     .run_if(in_state(GameState::Resumed))
                     .run_if(in_state(MovementType::Running)),
             )
-            .register_type::<Memory>()
-            .register_type::<MemoryTimer>();
+            .register_type::<Memory>();
     }
 }
 
 impl PlayerMemoryPlugin {
-    pub fn setup(mut commands: Commands, window: Query<&Window>) {
-        let window = window.single();
-
-        let tween = Tween::new(
-            EaseFunction::QuadraticInOut,
-            Duration::from_secs(1),
-            WidthLens {
-                start: 0.0,
-                end: 100.0,
-            },
-        );
-
-        commands
-            .spawn(NodeBundle {
-                style: Style {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    justify_content: JustifyContent::Start,
-                    align_items: AlignItems::Start,
-                    margin: UiRect::new(Val::Px(20.0), Val::Px(20.0), Val::Px(20.0), Val::Px(20.0)),
-                    ..default()
-                },
-                ..default()
-            })
-            .insert(Animator::new(tween))
-            .insert(Name::new("Memory Container"))
-            .with_children(|commands| {
-                commands
-                    .spawn(NodeBundle {
-                        style: Style {
-                            width: Val::Px(window.width() / 4.0),
-                            height: Val::Px(35.0),
-                            ..default()
-                        },
-                        background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
-                        ..default()
-                    })
-                    .insert(Name::new("Memory"))
-                    .with_children(|commands| {
-                        commands
-                            .spawn(NodeBundle {
-                                style: Style {
-                                    width: Val::Percent(100.0),
-                                    height: Val::Percent(100.0),
-                                    ..Default::default()
-                                },
-                                background_color: Color::WHITE.into(),
-                                ..Default::default()
-                            })
-                            .insert(SanityBar);
-                    });
-            });
+    pub fn memory() -> Memory {
+        Memory::new(PLAYER_MAX_MEMORY)
+            .with_drain(PLAYER_MEMORY_DRAINING_RATE)
+            .with_thresholds(&[PLAYER_MEMORY_WARNING, PLAYER_MEMORY_CRITICAL])
     }
 
-    pub fn update(
-        memory: Query<&Memory, With<Player>>,
-        mut bar: Query<&mut Style, With<SanityBar>>,
-    ) {
-        let Ok(memory) = memory.get_single() else {
-            return;
-        };
-
-        let Ok(mut bar) = bar.get_single_mut() else {
-            return;
-        };
-
-        bar.width = Val::Percent(memory.value / PLAYER_MAX_MEMORY * 100.0);
+    /// The faster the player runs the faster the memory drains.
+    fn scale_drain(mut player: Query<(&mut Memory, &AuxiliaryVelocity), With<Player>>) {
+        for (mut memory, velocity) in player.iter_mut() {
+            let speed = (velocity.value.x / PLAYER_MAX_VELOCITY_X).clamp(0.0, 1.0);
+            memory.scale = 1.0 + speed * PLAYER_MEMORY_SPEED_DRAIN;
+        }
     }
 
-    pub fn drain(mut query: Query<(&mut Memory, &mut MemoryTimer)>, time: Res<Time>) {
-        for (mut memory, mut timer) in query.iter_mut() {
-            if timer.0.tick(time.delta()).just_finished() {
-                memory.value = na::clamp(
-                    memory.value - PLAYER_MEMORY_DRAINING_RATE,
-                    0.0,
-                    PLAYER_MAX_VELOCITY_X,
+    /// Hard landings corrupt some memory, making it drain faster for a while.
+    fn on_landed(mut landings: EventReader<Landed>, mut memory: Query<&mut Memory, With<Player>>) {
+        for landing in landings.read() {
+            if landing.impact < CAMERA_HARD_LANDING_VELOCITY {
+                continue;
+            }
+
+            if let Ok(mut memory) = memory.get_mut(landing.player) {
+                memory.modify(
+                    MeterModifier::new("modifier-corrupted", PLAYER_MEMORY_CORRUPTION_DURATION)
+                        .drain(PLAYER_MEMORY_CORRUPTION_DRAIN),
                 );
             }
         }
     }
 }
-
-struct WidthLens {
-    start: f32,
-    end: f32,
-}
-
-impl Lens<Style> for WidthLens {
-    fn lerp(&mut self, target: &mut Style, ratio: f32) {
-        target.width = Val::Percent(self.start + (self.end - self.start) * ratio);
-    }
-}
@@ -1,5 +1,7 @@
 mod memory;
 mod score;
+mod skins;
 
 pub use memory::*;
 pub use score::*;
+pub use skins::*;
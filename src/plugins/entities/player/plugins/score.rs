@@ -1,9 +1,6 @@
-use std::time::Duration;
-
 use super::super::*;
-use bevy::prelude::*;
-use bevy_tweening::lens::UiPositionLens;
-use bevy_tweening::*;
+use crate::plugins::entities::terrain::Platform;
+use bevy::{prelude::*, utils::HashMap};
 
 pub(in super::super) struct PlayerScorePlugin;
 
@@ -12,83 +9,284 @@ pub struct Score {
     pub value: f32,
 }
 
-#[derive(Component)]
-pub struct ScoreLabel;
+/// World units travelled since the player spawned.
+#[derive(Default, Debug, Reflect, Component)]
+pub struct Distance {
+    pub value: f32,
+}
+
+/// Multiplies every bonus, grows with each one and decays back to `1` once no bonus
+/// came in for `SCORE_COMBO_WINDOW`.
+#[derive(Debug, Reflect, Component)]
+pub struct Combo {
+    pub multiplier: f32,
+    pub timer: Timer,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Self {
+            multiplier: 1.0,
+            timer: Timer::new(SCORE_COMBO_WINDOW, TimerMode::Once),
+        }
+    }
+}
+
+/// What the bonuses look at between a jump and the landing that follows it.
+#[derive(Default, Debug, Reflect, Component)]
+pub struct Flight {
+    /// ground the player last landed on
+    ground: Option<Entity>,
+    /// ground the player jumped off
+    takeoff: Option<Entity>,
+    airtime: f32,
+    /// clean landings in a row, each on a different platform
+    streak: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum Bonus {
+    Byte,
+    NearMiss,
+    Airtime,
+    CleanLanding,
+}
+
+impl Bonus {
+    pub const ALL: [Bonus; 4] = [
+        Bonus::Byte,
+        Bonus::NearMiss,
+        Bonus::Airtime,
+        Bonus::CleanLanding,
+    ];
+
+    /// Key of the name in the string tables.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Bonus::Byte => "bonus-byte",
+            Bonus::NearMiss => "bonus-near-miss",
+            Bonus::Airtime => "bonus-airtime",
+            Bonus::CleanLanding => "bonus-clean-landing",
+        }
+    }
+}
+
+/// Sent for every bonus awarded, `points` already include the combo multiplier.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScoreBonus {
+    pub bonus: Bonus,
+    pub points: f32,
+    pub position: Vec3,
+}
+
+/// Where the score of the current run came from, shown at game over.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct ScoreBreakdown {
+    pub distance: f32,
+    /// times each bonus got awarded and the points it brought
+    pub bonuses: HashMap<Bonus, (u32, f32)>,
+    pub best_combo: f32,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> f32 {
+        self.distance + self.bonuses.values().map(|(_, points)| points).sum::<f32>()
+    }
+}
+
+/// Sent every time the score goes past a multiple of `PLAYER_SCORE_MILESTONE`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Milestone;
 
 impl Plugin for PlayerScorePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(MovementType::Running),
-            Self::setup.after(PlayerPlugin::setup),
-        )
-        .add_systems(FixedUpdate, Self::update)
-        .register_type::<Score>();
+        app.add_systems(Update, Self::milestones)
+            .add_systems(
+                FixedUpdate,
+                (Self::travel, Self::bonuses, Self::decay)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(MovementType::Running))
+                    .run_if(in_state(Being::Alive)),
+            )
+            .add_systems(OnEnter(Being::Alive), Self::reset)
+            .add_event::<Milestone>()
+            .add_event::<ScoreBonus>()
+            .init_resource::<ScoreBreakdown>()
+            .register_type::<Score>()
+            .register_type::<Distance>()
+            .register_type::<Combo>()
+            .register_type::<Flight>();
     }
 }
 
 impl PlayerScorePlugin {
-    pub fn setup(
-        mut commands: Commands,
-        player: Query<Entity, With<Player>>,
-        fonts: Res<FontsAssets>,
+    fn reset(mut breakdown: ResMut<ScoreBreakdown>) {
+        *breakdown = ScoreBreakdown {
+            best_combo: 1.0,
+            ..Default::default()
+        };
+    }
+
+    fn travel(
+        mut player: Query<(&AuxiliaryVelocity, &mut Score, Has<Primary>), With<Player>>,
+        mut breakdown: ResMut<ScoreBreakdown>,
+        time: Res<Time>,
     ) {
-        if player.is_empty() {
-            return;
+        for (velocity, mut score, primary) in player.iter_mut() {
+            let points = (velocity.value.x / 100f32) * time.delta_seconds();
+            score.value += points;
+
+            if primary {
+                breakdown.distance += points;
+            }
         }
+    }
+
+    /// Awards the bonuses for bytes, jumps taken right at the edge of a platform, long
+    /// jumps and clean landings in a row, the latter only counting once a gap got
+    /// cleared. Only the primary runner's bonuses make it into the breakdown.
+    #[allow(clippy::too_many_arguments)]
+    fn bonuses(
+        mut player: Query<
+            (
+                Entity,
+                &Transform,
+                &mut Score,
+                &mut Combo,
+                &mut Flight,
+                Has<Primary>,
+            ),
+            With<Player>,
+        >,
+        grounded: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        grounds: Query<&Parent, With<Ground>>,
+        platforms: Query<(&Platform, &GlobalTransform)>,
+        mut collected: EventReader<Collected>,
+        mut jumped: EventReader<Jumped>,
+        mut landed: EventReader<Landed>,
+        mut bonuses: EventWriter<ScoreBonus>,
+        mut breakdown: ResMut<ScoreBreakdown>,
+        settings: Res<Settings>,
+        time: Res<Time>,
+    ) {
+        let collected: Vec<_> = collected.read().copied().collect();
+        let jumped: Vec<_> = jumped.read().copied().collect();
+        let landed: Vec<_> = landed.read().copied().collect();
+
+        for (entity, transform, mut score, mut combo, mut flight, primary) in player.iter_mut() {
+            let Some(grounded) = PlayerGrounded::of(&grounded, entity) else {
+                continue;
+            };
+
+            let mut award = |bonus: Bonus, points: f32, position: Vec3| {
+                let points = points * combo.multiplier;
+
+                score.value += points;
+
+                if primary {
+                    let (count, total) = breakdown.bonuses.entry(bonus).or_default();
+                    *count += 1;
+                    *total += points;
+                }
+
+                bonuses.send(ScoreBonus {
+                    bonus,
+                    points,
+                    position,
+                });
+
+                combo.multiplier = (combo.multiplier + SCORE_COMBO_STEP).min(SCORE_COMBO_MAX);
+                combo.timer.reset();
+
+                if primary {
+                    breakdown.best_combo = breakdown.best_combo.max(combo.multiplier);
+                }
+            };
+
+            for event in collected.iter().filter(|event| event.player == entity) {
+                award(Bonus::Byte, SCORE_BYTE_BONUS, event.position);
+            }
+
+            for event in jumped.iter().filter(|event| event.player == entity) {
+                flight.takeoff = flight.ground;
+
+                // the jump assist always jumps close to the edge, it shouldn't pay off
+                if settings.accessibility.auto_jump {
+                    continue;
+                }
+
+                let Some((platform, platform_transform)) = flight
+                    .takeoff
+                    .and_then(|ground| grounds.get(ground).ok())
+                    .and_then(|parent| platforms.get(parent.get()).ok())
+                else {
+                    continue;
+                };
 
-        let tween = Tween::new(
-            EaseFunction::QuadraticInOut,
-            Duration::from_secs(1),
-            UiPositionLens {
-                start: UiRect::top(Val::Percent(-100.0)),
-                end: UiRect::top(Val::Percent(0.0)),
-            },
-        );
-
-        commands
-            .spawn(NodeBundle {
-                style: Style {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    justify_content: JustifyContent::Center,
-                    margin: UiRect::new(Val::Px(20.0), Val::Px(20.0), Val::Px(20.0), Val::Px(20.0)),
-                    ..default()
-                },
-                ..default()
-            })
-            .insert(Animator::new(tween))
-            .insert(Name::new("Score Container"))
-            .with_children(|commands| {
-                commands
-                    .spawn(
-                        TextBundle::from_section(
-                            "0",
-                            TextStyle {
-                                font: fonts.vcr.clone(),
-                                font_size: 100.0,
-                                color: Color::BLACK,
-                            },
-                        )
-                        .with_style(Style::default()),
-                    )
-                    .insert(Label)
-                    .insert(ScoreLabel);
-            });
+                let edge = platform_transform.translation().x + platform.width / 2.0;
+
+                // coyote jumps happen past the edge and count as well
+                if edge - transform.translation.x < SCORE_NEAR_MISS_DISTANCE {
+                    award(Bonus::NearMiss, SCORE_NEAR_MISS_BONUS, event.position);
+                }
+            }
+
+            for event in landed.iter().filter(|event| event.player == entity) {
+                if flight.airtime >= SCORE_AIRTIME_MIN {
+                    let points = SCORE_AIRTIME_BONUS * flight.airtime;
+                    award(Bonus::Airtime, points, event.position);
+                }
+
+                let crossed = event.ground.is_some() && event.ground != flight.takeoff;
+
+                if event.impact >= CAMERA_HARD_LANDING_VELOCITY {
+                    flight.streak = 0;
+                } else if crossed {
+                    flight.streak += 1;
+
+                    if flight.streak > 1 {
+                        let points = SCORE_CLEAN_LANDING_BONUS * flight.streak as f32;
+                        award(Bonus::CleanLanding, points, event.position);
+                    }
+                }
+
+                flight.ground = event.ground;
+                flight.takeoff = None;
+                flight.airtime = 0.0;
+            }
+
+            if !grounded.value {
+                flight.airtime += time.delta_seconds();
+            }
+        }
     }
 
-    pub fn update(
-        score: Query<&Score, With<Player>>,
-        mut score_text: Query<&mut Text, With<ScoreLabel>>,
+    fn decay(mut player: Query<&mut Combo, With<Player>>, time: Res<Time>) {
+        for mut combo in player.iter_mut() {
+            if combo.timer.tick(time.delta()).finished() && combo.multiplier > 1.0 {
+                combo.multiplier =
+                    (combo.multiplier - SCORE_COMBO_DECAY * time.delta_seconds()).max(1.0);
+            }
+        }
+    }
+
+    fn milestones(
+        score: Query<&Score, With<Primary>>,
+        mut milestones: EventWriter<Milestone>,
+        mut reached: Local<u32>,
     ) {
         let Ok(score) = score.get_single() else {
             return;
         };
 
-        let Ok(mut score_text) = score_text.get_single_mut() else {
-            return;
-        };
+        let current = (score.value / PLAYER_SCORE_MILESTONE) as u32;
+
+        if current > *reached {
+            milestones.send(Milestone);
+        }
 
-        let formated = format!("{}", score.value.round());
-        score_text.sections[0].value = formated;
+        *reached = current;
     }
 }
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use bevy::{
+    render::{
+        render_resource::{AsBindGroup, ShaderRef},
+        texture::{ImageLoaderSettings, ImageSampler},
+    },
+    sprite::{Material2d, Material2dPlugin, MaterialMesh2dBundle, Mesh2dHandle},
+};
+use serde::Deserialize;
+
+use super::super::*;
+
+/// A sprite sheet laid out exactly like `player.png`, so every `PlayerAnimation` keeps
+/// pointing at the right frames.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SkinSheet {
+    pub id: String,
+    pub path: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SkinPalette {
+    pub id: String,
+    /// whether it has to be unlocked through an achievement first
+    #[serde(default)]
+    pub locked: bool,
+    /// pairs of hex colors, every pixel of the first color gets drawn with the second
+    pub swaps: Vec<(String, String)>,
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct Skins {
+    pub sheets: Vec<SkinSheet>,
+    pub palettes: Vec<SkinPalette>,
+}
+
+impl SkinSheet {
+    /// Key of the name in the string tables.
+    pub fn name(&self) -> String {
+        format!("skin-{}", self.id)
+    }
+}
+
+impl SkinPalette {
+    pub fn name(&self) -> String {
+        format!("palette-{}", self.id)
+    }
+}
+
+impl Skins {
+    pub fn sheet(&self, id: &str) -> Option<&SkinSheet> {
+        self.sheets.iter().find(|sheet| sheet.id == id)
+    }
+
+    pub fn palette(&self, id: &str) -> Option<&SkinPalette> {
+        self.palettes.iter().find(|palette| palette.id == id)
+    }
+
+    pub fn is_available(palette: &SkinPalette, progress: &AchievementProgress) -> bool {
+        !palette.locked
+            || progress
+                .unlocks
+                .contains(&Unlock::Palette(palette.id.clone()))
+    }
+}
+
+/// Draws the current atlas frame of the player with some of its colors swapped.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct PaletteMaterial {
+    #[uniform(0)]
+    pub frame: Vec4,
+    #[uniform(0)]
+    pub from: [Vec4; PLAYER_PALETTE_MAX_SWAPS],
+    #[uniform(0)]
+    pub to: [Vec4; PLAYER_PALETTE_MAX_SWAPS],
+    #[uniform(0)]
+    pub swaps: u32,
+    #[texture(1)]
+    #[sampler(2)]
+    pub sheet: Handle<Image>,
+}
+
+impl PaletteMaterial {
+    fn new(sheet: Handle<Image>, palette: &SkinPalette) -> Self {
+        let mut material = Self {
+            frame: Vec4::new(0.0, 0.0, 1.0, 1.0),
+            from: [Vec4::ZERO; PLAYER_PALETTE_MAX_SWAPS],
+            to: [Vec4::ZERO; PLAYER_PALETTE_MAX_SWAPS],
+            swaps: 0,
+            sheet,
+        };
+
+        let colors = palette
+            .swaps
+            .iter()
+            .filter_map(|(from, to)| Some((Color::hex(from).ok()?, Color::hex(to).ok()?)))
+            .take(PLAYER_PALETTE_MAX_SWAPS);
+
+        for (index, (from, to)) in colors.enumerate() {
+            material.from[index] = Vec4::from(from.as_linear_rgba_f32());
+            material.to[index] = Vec4::from(to.as_linear_rgba_f32());
+            material.swaps += 1;
+        }
+
+        material
+    }
+}
+
+impl Material2d for PaletteMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "embedded://shaders/palette.wgsl".into()
+    }
+}
+
+/// Handles to every sheet listed in `skins.json`, indexed by id.
+#[derive(Resource, Default)]
+pub struct SkinSheets(pub HashMap<String, Handle<Image>>);
+
+/// Child of the player drawing it through a `PaletteMaterial` while the sprite itself
+/// is kept transparent.
+#[derive(Component)]
+struct PaletteSwap;
+
+pub(in super::super) struct PlayerSkinsPlugin;
+
+impl Plugin for PlayerSkinsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<PaletteMaterial>::default())
+            .init_resource::<SkinSheets>()
+            .add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
+            .add_systems(
+                Update,
+                (Self::apply, Self::sync_frame)
+                    .chain()
+                    .run_if(in_state(GameAssetsState::Loaded)),
+            );
+    }
+}
+
+impl PlayerSkinsPlugin {
+    fn setup(
+        mut sheets: ResMut<SkinSheets>,
+        data: Res<DataAssets>,
+        skins: Res<Assets<Skins>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let Some(skins) = skins.get(&data.skins) else {
+            return;
+        };
+
+        for sheet in skins.sheets.iter() {
+            let handle = asset_server.load_with_settings(
+                format!("embedded://{}", sheet.path),
+                |settings: &mut ImageLoaderSettings| settings.sampler = ImageSampler::nearest(),
+            );
+
+            sheets.0.insert(sheet.id.clone(), handle);
+        }
+    }
+
+    /// Skin of the `runner`th player, the ones after the first get the following sheets
+    /// so everyone can tell their runner apart.
+    fn skin(skins: &Skins, settings: &SkinSettings, runner: usize) -> SkinSettings {
+        if runner == 0 || skins.sheets.is_empty() {
+            return settings.clone();
+        }
+
+        let index = skins
+            .sheets
+            .iter()
+            .position(|sheet| sheet.id == settings.sheet)
+            .unwrap_or(0);
+
+        SkinSettings {
+            sheet: skins.sheets[(index + runner) % skins.sheets.len()]
+                .id
+                .clone(),
+            palette: settings.palette.clone(),
+        }
+    }
+
+    /// Puts the skin picked in the settings on the players, falling back to the default
+    /// sheet and the original colors for anything unknown or still locked.
+    #[allow(clippy::too_many_arguments)]
+    fn apply(
+        mut commands: Commands,
+        mut player: Query<
+            (
+                Entity,
+                &Runner,
+                &mut Handle<Image>,
+                &mut Sprite,
+                &TextureAtlas,
+            ),
+            With<Player>,
+        >,
+        swaps: Query<(Entity, &Parent), With<PaletteSwap>>,
+        settings: Res<Settings>,
+        progress: Res<AchievementProgress>,
+        data: Res<DataAssets>,
+        skins: Res<Assets<Skins>>,
+        sheets: Res<SkinSheets>,
+        textures: Res<TextureAssets>,
+        atlases: Res<Assets<TextureAtlasLayout>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<PaletteMaterial>>,
+        mut applied: Local<HashMap<Entity, SkinSettings>>,
+    ) {
+        let Some(skins) = skins.get(&data.skins) else {
+            return;
+        };
+
+        applied.retain(|entity, _| player.contains(*entity));
+
+        for (entity, runner, mut texture, mut sprite, atlas) in player.iter_mut() {
+            let skin = Self::skin(skins, &settings.skin, runner.0);
+
+            if applied.get(&entity) == Some(&skin) {
+                continue;
+            }
+
+            applied.insert(entity, skin.clone());
+
+            Self::dress(
+                &mut commands,
+                entity,
+                &skin,
+                (&mut texture, &mut sprite, atlas),
+                &swaps,
+                skins,
+                &progress,
+                &sheets,
+                &textures,
+                &atlases,
+                &mut meshes,
+                &mut materials,
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dress(
+        commands: &mut Commands,
+        entity: Entity,
+        skin: &SkinSettings,
+        (texture, sprite, atlas): (&mut Handle<Image>, &mut Sprite, &TextureAtlas),
+        swaps: &Query<(Entity, &Parent), With<PaletteSwap>>,
+        skins: &Skins,
+        progress: &AchievementProgress,
+        sheets: &SkinSheets,
+        textures: &TextureAssets,
+        atlases: &Assets<TextureAtlasLayout>,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<PaletteMaterial>,
+    ) {
+        *texture = skins
+            .sheet(&skin.sheet)
+            .and_then(|sheet| sheets.0.get(&sheet.id))
+            .cloned()
+            .unwrap_or_else(|| textures.player.clone());
+
+        for (swap, parent) in swaps.iter() {
+            if parent.get() == entity {
+                commands.entity(swap).despawn_recursive();
+            }
+        }
+
+        let palette = skins
+            .palette(&skin.palette)
+            .filter(|palette| Skins::is_available(palette, progress))
+            .filter(|palette| !palette.swaps.is_empty());
+
+        let Some(palette) = palette else {
+            sprite.color.set_a(1.0);
+            return;
+        };
+
+        let Some(size) = atlases
+            .get(&atlas.layout)
+            .and_then(|layout| layout.textures.first())
+            .map(|rect| rect.size())
+        else {
+            return;
+        };
+
+        sprite.color.set_a(0.0);
+
+        commands.entity(entity).with_children(|parent| {
+            parent
+                .spawn(MaterialMesh2dBundle {
+                    mesh: Mesh2dHandle(meshes.add(Rectangle::from_size(size))),
+                    material: materials.add(PaletteMaterial::new(texture.clone(), palette)),
+                    transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                    ..Default::default()
+                })
+                .insert(Name::new("Palette Swap"))
+                .insert(PaletteSwap);
+        });
+    }
+
+    /// Keeps the swapped palette on the same atlas frame as the player's sprite.
+    fn sync_frame(
+        player: Query<(&TextureAtlas, &Handle<Image>, &Children), With<Player>>,
+        swaps: Query<&Handle<PaletteMaterial>, With<PaletteSwap>>,
+        images: Res<Assets<Image>>,
+        atlases: Res<Assets<TextureAtlasLayout>>,
+        mut materials: ResMut<Assets<PaletteMaterial>>,
+    ) {
+        for (atlas, texture, children) in player.iter() {
+            Self::sync(
+                atlas,
+                texture,
+                children,
+                &swaps,
+                &images,
+                &atlases,
+                &mut materials,
+            );
+        }
+    }
+
+    fn sync(
+        atlas: &TextureAtlas,
+        texture: &Handle<Image>,
+        children: &Children,
+        swaps: &Query<&Handle<PaletteMaterial>, With<PaletteSwap>>,
+        images: &Assets<Image>,
+        atlases: &Assets<TextureAtlasLayout>,
+        materials: &mut Assets<PaletteMaterial>,
+    ) {
+        let (Some(image), Some(rect)) = (
+            images.get(texture),
+            atlases
+                .get(&atlas.layout)
+                .and_then(|layout| layout.textures.get(atlas.index)),
+        ) else {
+            return;
+        };
+
+        let size = image.size_f32();
+        let frame = Vec4::new(
+            rect.min.x / size.x,
+            rect.min.y / size.y,
+            rect.max.x / size.x,
+            rect.max.y / size.y,
+        );
+
+        for handle in swaps.iter_many(children) {
+            if materials
+                .get(handle)
+                .is_some_and(|material| material.frame != frame)
+            {
+                if let Some(material) = materials.get_mut(handle) {
+                    material.frame = frame;
+                }
+            }
+        }
+    }
+}
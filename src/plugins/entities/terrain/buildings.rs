@@ -14,26 +14,43 @@ pub struct BuildingsPlugin;
 
 impl Plugin for BuildingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
+        app.init_resource::<Pool<Building>>()
+            .add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
             .add_systems(Update, Self::despawn)
             .add_systems(
-                Update,
-                (Self::scroll, Self::generate)
+                FixedUpdate,
+                Self::generate
+                    .in_set(GameplaySet)
+                    .after(FloatingOriginPlugin::rebase)
                     .run_if(in_state(GameState::Resumed))
                     .run_if(in_state(Being::Alive)),
             )
             .register_type::<Building>()
-            .register_type::<Platform>();
+            .register_type::<Platform>()
+            .console_command("seed", "seed <number>", &[], Self::seed_command)
+            .console_command(
+                "spawn",
+                "spawn <byte|obstacle>",
+                &["byte", "obstacle"],
+                Self::spawn_command,
+            );
 
         app.add_plugins(EntityInspector::<Platform>::default());
     }
 }
 
 impl BuildingsPlugin {
-    pub fn setup(mut commands: Commands, textures: Res<TextureAssets>) {
+    pub fn setup(
+        mut commands: Commands,
+        textures: Res<TextureAssets>,
+        mut pool: ResMut<Pool<Building>>,
+        mut parked: Query<&mut Building, With<Parked>>,
+    ) {
         Building::spawn(
             &mut commands,
             &textures,
+            &mut pool,
+            &mut parked,
             8,
             8.0 * BUILDING_WIDTH,
             PLATFORMS_MIN_Y,
@@ -42,17 +59,23 @@ impl BuildingsPlugin {
         .insert(PreventByte);
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn generate(
         mut commands: Commands,
         mut platforms: Query<(&Platform, &Transform)>,
         textures: Res<TextureAssets>,
-        velocity: Query<&AuxiliaryVelocity, With<Player>>,
+        player: Query<(), With<Player>>,
+        scroll: Res<ScrollSpeed>,
+        difficulty: Res<Difficulty>,
+        mut rng: ResMut<WorldRng>,
+        mut pool: ResMut<Pool<Building>>,
+        mut parked: Query<&mut Building, With<Parked>>,
     ) {
-        let Ok(velocity) = velocity.get_single() else {
+        if player.is_empty() {
             return;
-        };
+        }
 
-        let mut rng = rand::thread_rng();
+        let rng = &mut rng.0;
         let mut platforms = platforms.iter_mut().collect::<Vec<_>>();
 
         platforms.sort_by(|(_, a), (_, b)| a.translation.x.partial_cmp(&b.translation.x).unwrap());
@@ -60,67 +83,170 @@ impl BuildingsPlugin {
         let (prev, prev_trans) = platforms.last().unwrap();
 
         if platforms.len() < WORLD_MAX_PLATFORMS as usize {
-            let growth = |dir: f32| 1.0 + dir * velocity.value.x / PLAYER_MAX_VELOCITY_X;
+            let growth = |dir: f32| 1.0 + dir * scroll.0 / PLAYER_MAX_VELOCITY_X;
 
             let segments: usize = rng.gen_range(0..=10);
             let width = (segments + 2) as f32 * BUILDING_WIDTH * WORLD_SPRITE_SCALE.x;
 
             let mut spacing = PLATFORMS_MIN_SPACING * 3.0;
 
-            if velocity.value.x >= (PLAYER_INIT_VELOCITY_X + PLAYER_VELOCITY_BUMP) * 1.02 {
+            if scroll.0 >= (PLAYER_INIT_VELOCITY_X + PLAYER_VELOCITY_BUMP) * 1.02 {
                 spacing = (PLATFORMS_MAX_SPACING - PLATFORMS_MIN_SPACING)
                     + rng.gen_range(PLATFORMS_MIN_SPACING..=PLATFORMS_MAX_SPACING) * growth(1.0);
             }
 
-            let x = prev_trans.translation.x + (prev.width + width) / 2.0 + spacing;
+            let x = prev_trans.translation.x + (prev.width + width) / 2.0 + spacing * difficulty.0;
             let y = rng.gen_range(PLATFORMS_MIN_Y..=PLATFORMS_MAX_Y);
-            Building::spawn(&mut commands, &textures, segments, x, y, 10.0);
+            Building::spawn(
+                &mut commands,
+                &textures,
+                &mut pool,
+                &mut parked,
+                segments,
+                x,
+                y,
+                10.0,
+            );
         }
     }
 
-    fn despawn(mut commands: Commands, platforms: Query<(Entity, &Transform), With<Scrollable>>) {
-        if platforms.is_empty() {
+    /// Buildings left behind go back to the pool along with the bytes still floating
+    /// above them, the rest of the scenery is despawned.
+    pub fn despawn(
+        mut commands: Commands,
+        scenery: Query<(Entity, &Transform, Option<&Building>), With<Scenery>>,
+        children: Query<&Children>,
+        bytes: Query<(), With<Byte>>,
+        player: Query<&Transform, With<Player>>,
+        mut buildings: ResMut<Pool<Building>>,
+        mut pool: ResMut<Pool<Byte>>,
+    ) {
+        let Some(last) = player
+            .iter()
+            .map(|transform| transform.translation.x)
+            .reduce(f32::min)
+        else {
             return;
-        }
+        };
+
+        for (entity, transform, building) in scenery.iter() {
+            if transform.translation.x > last - SCENERY_DESPAWN_DISTANCE {
+                continue;
+            }
 
-        for (entity, transform) in platforms.iter() {
-            if transform.translation.x <= 10f32.powi(3) * -8.0 {
+            let Some(building) = building else {
                 commands.entity(entity).despawn_recursive();
+                continue;
+            };
+
+            for byte in children
+                .iter_descendants(entity)
+                .filter(|&child| bytes.contains(child))
+            {
+                BytesPlugin::park(&mut commands, &mut pool, byte);
+            }
+
+            if buildings.park(&mut commands, entity) {
+                commands
+                    .entity(entity)
+                    .remove::<(Platform, Scenery, PreventByte)>();
+                commands.entity(building.ground).insert(ColliderDisabled);
             }
         }
     }
+    /// Reseeds the generator, the buildings already standing stay as they are.
+    fn seed_command(In(args): In<Vec<String>>, mut rng: ResMut<WorldRng>) -> CommandResult {
+        let seed: u64 = arg(&args, 0, "seed <number>")?;
+        *rng = WorldRng::seeded(seed);
 
-    fn scroll(
-        mut platforms: Query<&mut Transform, With<Scrollable>>,
-        time: Res<Time>,
-        velocity: Query<&AuxiliaryVelocity, With<Player>>,
-    ) {
-        if platforms.is_empty() {
-            return;
-        }
+        Ok(format!("world seeded with {seed}"))
+    }
 
-        let Ok(velocity) = velocity.get_single() else {
-            return;
-        };
+    /// Drops a byte or an obstacle a little ahead of the leading runner.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_command(
+        In(args): In<Vec<String>>,
+        mut commands: Commands,
+        platforms: Query<(Entity, &Platform, &Transform)>,
+        player: Query<&Transform, With<Player>>,
+        textures: Res<TextureAssets>,
+        layouts: Res<SpriteLayouts>,
+        mut bytes: ResMut<Pool<Byte>>,
+        mut buildings: ResMut<Pool<Building>>,
+        mut parked: Query<&mut Building, With<Parked>>,
+    ) -> CommandResult {
+        const AHEAD: f32 = 800.0;
+
+        let leader = player
+            .iter()
+            .map(|transform| transform.translation.x)
+            .reduce(f32::max)
+            .ok_or("no runner to spawn ahead of")?;
+        let x = leader + AHEAD;
+
+        match args.first().map(String::as_str) {
+            Some("byte") => {
+                // the platform under `x` or else the next one after it
+                let (building, _, transform) = platforms
+                    .iter()
+                    .filter(|(_, platform, transform)| {
+                        transform.translation.x + platform.width / 2.0 >= x
+                    })
+                    .min_by(|(_, _, a), (_, _, b)| a.translation.x.total_cmp(&b.translation.x))
+                    .ok_or("no platform ahead")?;
+
+                let offset = (x - transform.translation.x).max(0.0) / WORLD_SPRITE_SCALE.x;
+                BytesPlugin::place(
+                    &mut commands,
+                    &textures,
+                    &layouts,
+                    &mut bytes,
+                    building,
+                    offset,
+                );
+
+                Ok("byte placed".into())
+            }
+            Some("obstacle") => {
+                Building::spawn(
+                    &mut commands,
+                    &textures,
+                    &mut buildings,
+                    &mut parked,
+                    0,
+                    x,
+                    PLATFORMS_MAX_Y,
+                    10.0,
+                );
 
-        for mut platform in platforms.iter_mut() {
-            platform.translation.x += -2.0 * velocity.value.x * time.delta_seconds();
+                Ok("obstacle placed".into())
+            }
+            _ => Err("usage: spawn <byte|obstacle>".into()),
         }
     }
 }
 
-#[derive(Default, Debug, Component, Reflect)]
+/// Root of a building, holding on to its children so a parked one can be resized
+/// into whatever the generation asks for next.
+#[derive(Debug, Component, Reflect)]
 pub struct Building {
     reps: usize,
     width: f32,
-    pos_x: f32,
-    pos_y: f32,
+    ground: Entity,
+    segments: Vec<Entity>,
 }
 
+const BUILDING_COLLIDER_HEIGHT: f32 = 24.0;
+
 impl Building {
+    /// Sets up a building of `reps + 2` segments, recycled from the pool when one is
+    /// parked there.
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn<'a>(
         commands: &'a mut Commands,
-        textures: &'a Res<TextureAssets>,
+        textures: &TextureAssets,
+        pool: &mut Pool<Building>,
+        parked: &mut Query<&mut Building, With<Parked>>,
         reps: usize,
         pos_x: f32,
         pos_y: f32,
@@ -129,59 +255,198 @@ impl Building {
         let reps = reps + 2;
         let width = reps as f32 * BUILDING_WIDTH;
 
-        let mut entity_commands = commands.spawn(Name::new("Building"));
-        entity_commands
-            .insert(Visibility::Visible)
-            .insert(InheritedVisibility::default())
-            .insert(TransformBundle {
-                local: Transform {
-                    translation: Vec3::new(pos_x, pos_y, pos_z),
-                    scale: WORLD_SPRITE_SCALE,
-                    ..Default::default()
-                },
-                ..Default::default()
-            })
-            .insert(Platform {
-                coords: (pos_x, pos_y),
-                width: width * WORLD_SPRITE_SCALE.x,
-            })
-            .insert(Scrollable)
-            .insert(Anchor::TopCenter)
-            .with_children(|parent| {
-                let half_ext = BUILDING_WIDTH / 2.0;
-                let mut prev_x = width / -2.0 + half_ext * -1.0;
+        let transform = Transform {
+            translation: Vec3::new(pos_x, pos_y, pos_z),
+            scale: WORLD_SPRITE_SCALE,
+            ..Default::default()
+        };
+        let platform = Platform {
+            coords: (pos_x, pos_y),
+            width: width * WORLD_SPRITE_SCALE.x,
+        };
+        let collider = Collider::cuboid(width / 2.0, BUILDING_COLLIDER_HEIGHT / 2.0);
 
-                let collider_height = 24.0;
-                parent
-                    .spawn(Collider::cuboid(width / 2.0, collider_height / 2.0))
+        let recycled = pool.take(commands).and_then(|entity| {
+            parked
+                .get_mut(entity)
+                .ok()
+                .map(|building| (entity, building))
+        });
+
+        let entity = match recycled {
+            Some((entity, mut building)) => {
+                commands
+                    .entity(building.ground)
+                    .insert(collider)
+                    .remove::<ColliderDisabled>();
+
+                for i in 0..reps.max(building.segments.len()) {
+                    match building.segments.get(i) {
+                        Some(&segment) if i < reps => {
+                            commands.entity(segment).insert((
+                                Self::texture(textures, i, reps),
+                                Self::offset(i, width),
+                                Visibility::Inherited,
+                            ));
+                        }
+                        Some(&segment) => {
+                            commands.entity(segment).insert(Visibility::Hidden);
+                        }
+                        None => {
+                            let segment = Self::segment(commands, textures, i, reps, width);
+                            commands.entity(entity).add_child(segment);
+                            building.segments.push(segment);
+                        }
+                    }
+                }
+
+                building.reps = reps;
+                building.width = width;
+
+                commands
+                    .entity(entity)
+                    .insert((transform, platform, Scenery));
+                entity
+            }
+            None => {
+                let ground = commands
+                    .spawn(collider)
                     .insert(TransformBundle::from(Transform::from_xyz(
                         0.0,
-                        (BUILDING_HEIGHT - collider_height) / 2.0,
+                        (BUILDING_HEIGHT - BUILDING_COLLIDER_HEIGHT) / 2.0,
                         0.0,
                     )))
-                    .insert(Ground);
-
-                for i in 0..reps {
-                    let x = prev_x + BUILDING_WIDTH;
-                    prev_x = x;
-
-                    parent
-                        .spawn(Name::new("Building Segment"))
-                        .insert(SpriteBundle {
-                            texture: if i == 0 {
-                                textures.building_left.clone()
-                            } else if i == reps - 1 {
-                                textures.building_right.clone()
-                            } else {
-                                textures.building_middle.clone()
-                            },
-                            transform: Transform::from_xyz(x, 0.0, 0.0),
-                            ..Default::default()
-                        })
-                        .insert(Anchor::TopCenter);
-                }
-            });
+                    .insert(Ground)
+                    .id();
+                let segments = (0..reps)
+                    .map(|i| Self::segment(commands, textures, i, reps, width))
+                    .collect::<Vec<_>>();
+
+                commands
+                    .spawn(Name::new("Building"))
+                    .insert(Visibility::Visible)
+                    .insert(InheritedVisibility::default())
+                    .insert(TransformBundle {
+                        local: transform,
+                        ..Default::default()
+                    })
+                    .insert(platform)
+                    .insert(Scenery)
+                    .insert(Anchor::TopCenter)
+                    .push_children(&[ground])
+                    .push_children(&segments)
+                    .insert(Building {
+                        reps,
+                        width,
+                        ground,
+                        segments,
+                    })
+                    .id()
+            }
+        };
+
+        commands.entity(entity)
+    }
+
+    fn segment(
+        commands: &mut Commands,
+        textures: &TextureAssets,
+        i: usize,
+        reps: usize,
+        width: f32,
+    ) -> Entity {
+        commands
+            .spawn(Name::new("Building Segment"))
+            .insert(SpriteBundle {
+                texture: Self::texture(textures, i, reps),
+                transform: Self::offset(i, width),
+                ..Default::default()
+            })
+            .insert(Anchor::TopCenter)
+            .id()
+    }
+
+    fn texture(textures: &TextureAssets, i: usize, reps: usize) -> Handle<Image> {
+        if i == 0 {
+            textures.building_left.clone()
+        } else if i == reps - 1 {
+            textures.building_right.clone()
+        } else {
+            textures.building_middle.clone()
+        }
+    }
+
+    fn offset(i: usize, width: f32) -> Transform {
+        Transform::from_xyz(width / -2.0 + (i as f32 + 0.5) * BUILDING_WIDTH, 0.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod test_buildings {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    const FRAMES: usize = 5_000;
+    const WARMUP: usize = 200;
+
+    /// Frame times of a headless world racing through buildings at `capacity`.
+    fn race(capacity: usize) -> Vec<Duration> {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(ScrollSpeed(PLAYER_MAX_VELOCITY_X))
+            .insert_resource(WorldRng::seeded(1234))
+            .insert_resource(Pool::<Building>::with_capacity(capacity))
+            .insert_resource(Pool::<Byte>::with_capacity(capacity))
+            .init_resource::<Difficulty>()
+            .init_resource::<TextureAssets>()
+            .add_systems(Startup, BuildingsPlugin::setup)
+            .add_systems(
+                Update,
+                (
+                    BuildingsPlugin::generate,
+                    |mut player: Query<&mut Transform, With<Player>>| {
+                        for mut transform in player.iter_mut() {
+                            transform.translation.x += BUILDING_WIDTH * 4.0;
+                        }
+                    },
+                    BuildingsPlugin::despawn,
+                )
+                    .chain(),
+            );
+        app.world.spawn((Player, Transform::default()));
+
+        (0..FRAMES)
+            .map(|_| {
+                let start = Instant::now();
+                app.update();
+                start.elapsed()
+            })
+            .skip(WARMUP)
+            .collect()
+    }
+
+    fn report(label: &str, mut frames: Vec<Duration>) -> Duration {
+        frames.sort();
+        let mean = frames.iter().sum::<Duration>() / frames.len() as u32;
+        let p99 = frames[frames.len() * 99 / 100];
+        println!(
+            "{label}: mean {mean:?}, p99 {p99:?}, worst {:?}",
+            frames.last().unwrap()
+        );
+        mean
+    }
+
+    /// `cargo test --release bench_pooling -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_pooling() {
+        let spawned = report("spawned", race(0));
+        let pooled = report("pooled", race(TERRAIN_POOL_CAPACITY));
 
-        entity_commands
+        println!(
+            "pooling saves {:.1}% of the frame time",
+            (1.0 - pooled.as_secs_f64() / spawned.as_secs_f64()) * 100.0
+        );
     }
 }
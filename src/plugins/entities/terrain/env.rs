@@ -41,7 +41,7 @@ impl EnvironmentPlugin {
                 ..Default::default()
             })
             .insert(Name::new("Cabinet"))
-            .insert(Scrollable)
+            .insert(Scenery)
             .insert(Anchor::BottomCenter)
             .insert(Cabinet)
             .with_children(|commands| {
@@ -72,7 +72,7 @@ impl EnvironmentPlugin {
                 ..Default::default()
             })
             .insert(Name::new("Street Board"))
-            .insert(Scrollable);
+            .insert(Scenery);
     }
 
     fn open_door(
@@ -81,16 +81,15 @@ impl EnvironmentPlugin {
         mut cabinet: Query<(Entity, &mut TextureAtlas), With<Cabinet>>,
         ctx: Res<RapierContext>,
     ) {
-        if let (Ok(player), Ok(door), Ok((_, mut atlas))) = (
-            player.get_single(),
-            door.get_single(),
-            cabinet.get_single_mut(),
-        ) {
-            if ctx.intersection_pair(player, door) == Some(true) {
-                atlas.index = 1;
-            } else {
-                atlas.index = 0;
-            }
+        if let (Ok(door), Ok((_, mut atlas))) = (door.get_single(), cabinet.get_single_mut()) {
+            let inside = player
+                .iter()
+                .any(|player| ctx.intersection_pair(player, door) == Some(true));
+
+            atlas.index = match inside {
+                true => 1,
+                false => 0,
+            };
         }
     }
 }
@@ -1,5 +1,6 @@
 use bevy::{prelude::*, sprite::Anchor};
 use bevy_rapier2d::prelude::*;
+use rand::{rngs::StdRng, SeedableRng};
 
 use glib::*;
 
@@ -9,22 +10,67 @@ pub struct Platform {
     pub width: f32,
 }
 
+impl Platform {
+    pub fn left(&self) -> f32 {
+        self.coords.0 - self.width / 2.0
+    }
+
+    pub fn right(&self) -> f32 {
+        self.coords.0 + self.width / 2.0
+    }
+
+    /// Height of the roof runners stand on.
+    pub fn top(&self) -> f32 {
+        self.coords.1 + BUILDING_HEIGHT / 2.0 * WORLD_SPRITE_SCALE.y
+    }
+}
+
+/// Part of the world, despawned once every runner left it far enough behind.
 #[derive(Component)]
-pub struct Scrollable;
+pub struct Scenery;
+
+/// Speed the world goes by at, the fastest runner's. Generation and the parallax
+/// follow it, it drops to zero once everyone is out.
+#[derive(Resource, Default, Debug)]
+pub struct ScrollSpeed(pub f32);
+
+/// Randomness behind the layout of the world along with the seed it started from, so
+/// a run can be replayed.
+#[derive(Resource)]
+pub struct WorldRng(pub StdRng, pub u64);
+
+impl Default for WorldRng {
+    fn default() -> Self {
+        Self::seeded(rand::random())
+    }
+}
+
+impl WorldRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed), seed)
+    }
+}
 
 // mod obstacles;
 mod buildings;
 mod env;
+mod origin;
+mod pool;
 
 // pub use obstacles::*;
 pub use buildings::*;
 pub use env::*;
+pub use origin::*;
+pub use pool::*;
 
 pub struct TerrainPlugin;
 
 impl Plugin for TerrainPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(BuildingsPlugin)
-            .add_plugins(EnvironmentPlugin);
+        app.init_resource::<WorldRng>()
+            .init_resource::<ScrollSpeed>()
+            .add_plugins(BuildingsPlugin)
+            .add_plugins(EnvironmentPlugin)
+            .add_plugins(FloatingOriginPlugin);
     }
 }
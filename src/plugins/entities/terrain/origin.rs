@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+
+use super::*;
+use crate::plugins::entities::{camera::MainCamera, player::*};
+use crate::{GameState, GameplaySet, Interpolated};
+
+/// Every entity at the root of the world, children follow their parents.
+type Root<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        Option<&'static mut Interpolated>,
+        Has<Player>,
+        Has<MainCamera>,
+    ),
+    (Without<Parent>, Without<Node>),
+>;
+
+/// Runners move through the world instead of the world scrolling under them, so
+/// their coordinates keep growing. Once the leader is far enough from the origin
+/// everything gets shifted back, keeping the numbers small for rendering and Rapier.
+pub struct FloatingOriginPlugin;
+
+impl Plugin for FloatingOriginPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            Self::rebase
+                .in_set(GameplaySet)
+                .run_if(in_state(GameState::Resumed))
+                .run_if(in_state(Being::Alive)),
+        );
+    }
+}
+
+impl FloatingOriginPlugin {
+    pub(crate) fn rebase(mut world: Root, mut platforms: Query<&mut Platform>) {
+        let Some(leader) = world
+            .iter()
+            .filter(|(_, _, player, _)| *player)
+            .map(|(transform, ..)| transform.translation.x)
+            .reduce(f32::max)
+        else {
+            return;
+        };
+
+        if leader < ORIGIN_REBASE_DISTANCE {
+            return;
+        }
+
+        // whole units keep the pixel art from shimmering across the shift
+        let offset = Vec3::new(-leader.floor(), 0.0, 0.0);
+
+        Self::shift(offset, &mut world, &mut platforms);
+    }
+
+    /// Brings the camera back to where it sits for runners that just spawned, the
+    /// new run is laid out around the origin again.
+    pub fn recenter(mut world: Root, mut platforms: Query<&mut Platform>) {
+        let Some(camera) = world
+            .iter()
+            .filter(|(.., camera)| *camera)
+            .map(|(transform, ..)| transform.translation.x)
+            .reduce(f32::min)
+        else {
+            return;
+        };
+
+        let offset = Vec3::new(
+            (PLAYER_SPAWN_X + CAMERA_PLAYER_OFFSET.x - camera).floor(),
+            0.0,
+            0.0,
+        );
+
+        if offset.x == 0.0 {
+            return;
+        }
+
+        Self::shift(offset, &mut world, &mut platforms);
+    }
+
+    fn shift(offset: Vec3, world: &mut Root, platforms: &mut Query<&mut Platform>) {
+        for (mut transform, interpolated, ..) in world.iter_mut() {
+            transform.translation += offset;
+
+            if let Some(mut interpolated) = interpolated {
+                interpolated.shift(offset);
+            }
+        }
+
+        for mut platform in platforms.iter_mut() {
+            platform.coords.0 += offset.x;
+        }
+    }
+}
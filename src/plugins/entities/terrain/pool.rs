@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use glib::TERRAIN_POOL_CAPACITY;
+
+/// Out of sight and out of play, waiting in a `Pool` to be set up again.
+#[derive(Component, Default)]
+pub struct Parked;
+
+/// Entities left behind, kept aside instead of despawned and handed back out the
+/// next time one of the kind `T` is needed. At full speed spawning whole hierarchies
+/// churns archetypes and allocations, recycling them only flips a few components.
+#[derive(Resource)]
+pub struct Pool<T> {
+    parked: Vec<Entity>,
+    capacity: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Self::with_capacity(TERRAIN_POOL_CAPACITY)
+    }
+}
+
+impl<T> Pool<T> {
+    /// A pool holding up to `capacity` entities, with none everything gets despawned.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            parked: Vec::with_capacity(capacity),
+            capacity,
+            marker: PhantomData,
+        }
+    }
+
+    /// Hides `entity` away, or despawns it once the pool is full. Returns whether it
+    /// got parked, the caller then takes off whatever keeps it in play.
+    pub fn park(&mut self, commands: &mut Commands, entity: Entity) -> bool {
+        if self.parked.len() >= self.capacity {
+            commands.entity(entity).despawn_recursive();
+            return false;
+        }
+
+        commands.entity(entity).insert((Parked, Visibility::Hidden));
+        self.parked.push(entity);
+        true
+    }
+
+    /// A parked entity made visible again, parked ones that got despawned meanwhile
+    /// along with their parent are skipped.
+    pub fn take(&mut self, commands: &mut Commands) -> Option<Entity> {
+        while let Some(entity) = self.parked.pop() {
+            if let Some(mut parked) = commands.get_entity(entity) {
+                parked.remove::<Parked>().insert(Visibility::Inherited);
+                return Some(entity);
+            }
+        }
+
+        None
+    }
+}
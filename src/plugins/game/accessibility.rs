@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
+
+use crate::{
+    plugins::entities::{
+        camera::MainCamera,
+        player::{
+            AssistedJump, AuxiliaryVelocity, Being, Jump, MovementType, Player, PlayerGrounded,
+        },
+        terrain::Platform,
+    },
+    GameState, GameplaySet, Ground, Grounded, Settings,
+};
+use glib::*;
+
+/// Platform outlines drawn on top of the world while high contrast is on.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct OutlineGizmos;
+
+/// Pace of the virtual clock, the game speed setting times the debug time scale so
+/// neither overrides the other.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GameSpeed {
+    pub accessibility: f32,
+    pub debug: f32,
+}
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self {
+            accessibility: 1.0,
+            debug: 1.0,
+        }
+    }
+}
+
+impl GameSpeed {
+    pub fn relative(&self) -> f32 {
+        self.accessibility * self.debug
+    }
+}
+
+/// Applies the accessibility settings that aren't already looked up by the systems they
+/// affect.
+pub struct GameAccessibilityPlugin;
+
+impl Plugin for GameAccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_gizmo_group::<OutlineGizmos>()
+            .init_resource::<GameSpeed>()
+            .add_systems(Startup, Self::setup)
+            .add_systems(
+                Update,
+                (
+                    (Self::game_speed, Self::bloom).run_if(resource_changed::<Settings>),
+                    Self::pace
+                        .after(Self::game_speed)
+                        .run_if(resource_changed::<GameSpeed>),
+                    Self::outline
+                        .run_if(|settings: Res<Settings>| settings.accessibility.high_contrast),
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                Self::auto_jump
+                    .in_set(GameplaySet)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(MovementType::Running))
+                    .run_if(in_state(Being::Alive)),
+            );
+    }
+}
+
+impl GameAccessibilityPlugin {
+    fn setup(mut config: ResMut<GizmoConfigStore>) {
+        let (config, _) = config.config_mut::<OutlineGizmos>();
+        config.line_width = ACCESSIBILITY_OUTLINE_WIDTH;
+    }
+
+    fn game_speed(settings: Res<Settings>, mut speed: ResMut<GameSpeed>) {
+        let accessibility = settings.accessibility.game_speed.clamp(
+            ACCESSIBILITY_GAME_SPEEDS[ACCESSIBILITY_GAME_SPEEDS.len() - 1],
+            ACCESSIBILITY_GAME_SPEEDS[0],
+        );
+
+        if speed.accessibility != accessibility {
+            speed.accessibility = accessibility;
+        }
+    }
+
+    /// Slows the virtual clock down, everything gameplay related runs on it.
+    fn pace(speed: Res<GameSpeed>, mut time: ResMut<Time<Virtual>>) {
+        let relative = speed.relative();
+
+        if time.relative_speed() != relative {
+            time.set_relative_speed(relative);
+        }
+    }
+
+    fn bloom(settings: Res<Settings>, mut camera: Query<&mut BloomSettings, With<MainCamera>>) {
+        let intensity = match settings.accessibility.reduced_motion {
+            true => 0.0,
+            false => BloomSettings::NATURAL.intensity,
+        };
+
+        for mut bloom in camera.iter_mut() {
+            if bloom.intensity != intensity {
+                bloom.intensity = intensity;
+            }
+        }
+    }
+
+    fn outline(mut gizmos: Gizmos<OutlineGizmos>, platforms: Query<(&Platform, &GlobalTransform)>) {
+        for (platform, transform) in platforms.iter() {
+            let center = transform.translation();
+            let top = center.y + BUILDING_HEIGHT / 2.0 * WORLD_SPRITE_SCALE.y;
+            let bottom = top - ACCESSIBILITY_OUTLINE_DEPTH;
+            let left = center.x - platform.width / 2.0;
+            let right = center.x + platform.width / 2.0;
+
+            gizmos.linestrip_2d(
+                [
+                    Vec2::new(left, bottom),
+                    Vec2::new(left, top),
+                    Vec2::new(right, top),
+                    Vec2::new(right, bottom),
+                ],
+                ACCESSIBILITY_OUTLINE_COLOR,
+            );
+        }
+    }
+
+    /// Jumps for the players once the edge of the platform they're running on is about
+    /// to be reached, at most once per platform.
+    fn auto_jump(
+        settings: Res<Settings>,
+        player: Query<(Entity, &Transform, &AuxiliaryVelocity, &Jump), With<Player>>,
+        grounded: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        grounds: Query<&Parent, With<Ground>>,
+        platforms: Query<(&Platform, &GlobalTransform)>,
+        mut assisted: EventWriter<AssistedJump>,
+        mut jumped_off: Local<HashMap<Entity, Entity>>,
+    ) {
+        if !settings.accessibility.auto_jump {
+            return;
+        }
+
+        jumped_off.retain(|entity, _| player.contains(*entity));
+
+        for (entity, transform, velocity, jump) in player.iter() {
+            let Some(grounded) = PlayerGrounded::of(&grounded, entity) else {
+                continue;
+            };
+
+            let Some(ground) = grounded.ground.filter(|_| grounded.value) else {
+                continue;
+            };
+
+            if jump.rising || jumped_off.get(&entity) == Some(&ground) {
+                continue;
+            }
+
+            let Some((platform, platform_transform)) = grounds
+                .get(ground)
+                .ok()
+                .and_then(|parent| platforms.get(parent.get()).ok())
+            else {
+                continue;
+            };
+
+            let edge = platform_transform.translation().x + platform.width / 2.0;
+            let lead = PLAYER_VELOCITY_SCALE * velocity.value.x * ACCESSIBILITY_AUTO_JUMP_LEAD;
+
+            if edge - transform.translation.x <= lead {
+                assisted.send(AssistedJump { player: entity });
+                jumped_off.insert(entity, ground);
+            }
+        }
+    }
+}
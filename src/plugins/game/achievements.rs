@@ -0,0 +1,356 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::PathBuf,
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::UiPositionLens, *};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    plugins::entities::player::{
+        AuxiliaryVelocity, Being, Collected, Distance, Jumped, Landed, Memory, Primary, Score,
+    },
+    DataAssets, FontsAssets, GameAssetsState, GameState, Localization,
+};
+use glib::*;
+
+const PROGRESS_FILE: &str = "achievements.json";
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Goal {
+    Score(f32),
+    BytesInRun(u32),
+    /// stay alive for `seconds` with less than `memory` percent of memory left
+    LowMemory {
+        memory: f32,
+        seconds: f32,
+    },
+    /// land on another platform after jumping at this fraction of the max speed
+    GapAtSpeed(f32),
+}
+
+impl Goal {
+    fn target(self) -> f32 {
+        match self {
+            Self::Score(score) => score,
+            Self::BytesInRun(bytes) => bytes as f32,
+            Self::LowMemory { seconds, .. } => seconds,
+            Self::GapAtSpeed(_) => 1.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Unlock {
+    Palette(String),
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Achievement {
+    pub id: String,
+    pub goal: Goal,
+    #[serde(default)]
+    pub unlocks: Option<Unlock>,
+}
+
+impl Achievement {
+    /// Key of the name in the string tables.
+    pub fn name(&self) -> String {
+        format!("achievement-{}", self.id)
+    }
+
+    pub fn description(&self) -> String {
+        format!("achievement-{}-description", self.id)
+    }
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct Achievements(pub Vec<Achievement>);
+
+/// What has been unlocked so far along with the best value reached for every goal,
+/// kept on disk between sessions.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct AchievementProgress {
+    pub unlocked: BTreeSet<String>,
+    pub best: BTreeMap<String, f32>,
+    pub unlocks: BTreeSet<Unlock>,
+    pub best_score: f32,
+    /// world units travelled in the longest run
+    pub best_distance: f32,
+}
+
+impl AchievementProgress {
+    fn path() -> PathBuf {
+        utils::data_dir().join(PROGRESS_FILE)
+    }
+
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = Self::path();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    fn persist(&self) {
+        if let Err(err) = self.save() {
+            warn!("failed to save achievements: {err}");
+        }
+    }
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct AchievementUnlocked(pub Achievement);
+
+/// Values reached during the current run, indexed by achievement id.
+#[derive(Resource, Default, Debug)]
+struct RunProgress {
+    values: HashMap<String, f32>,
+    score: f32,
+    distance: f32,
+    bytes: u32,
+    ground: Option<Entity>,
+    jump: Option<(Option<Entity>, f32)>,
+}
+
+#[derive(Component)]
+struct Toasts;
+
+#[derive(Component)]
+struct Toast(Timer);
+
+pub struct GameAchievementsPlugin;
+
+impl Plugin for GameAchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AchievementProgress::load())
+            .init_resource::<RunProgress>()
+            .add_event::<AchievementUnlocked>()
+            .add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
+            .add_systems(OnEnter(Being::Alive), Self::reset)
+            .add_systems(OnEnter(Being::Dead), Self::save)
+            .add_systems(
+                Update,
+                (
+                    Self::track
+                        .run_if(in_state(GameState::Resumed))
+                        .run_if(in_state(Being::Alive)),
+                    Self::toast,
+                    Self::dismiss,
+                )
+                    .chain()
+                    .run_if(in_state(GameAssetsState::Loaded)),
+            );
+    }
+}
+
+impl GameAchievementsPlugin {
+    fn setup(mut commands: Commands) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(20.0),
+                    bottom: Val::Px(20.0),
+                    flex_direction: FlexDirection::ColumnReverse,
+                    align_items: AlignItems::End,
+                    row_gap: Val::Px(10.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Name::new("Toasts"))
+            .insert(Toasts);
+    }
+
+    fn reset(mut run: ResMut<RunProgress>) {
+        *run = RunProgress::default();
+    }
+
+    fn save(run: Res<RunProgress>, mut progress: ResMut<AchievementProgress>) {
+        for (id, value) in run.values.iter() {
+            let best = progress.best.entry(id.clone()).or_default();
+            *best = best.max(*value);
+        }
+
+        progress.best_score = progress.best_score.max(run.score);
+        progress.best_distance = progress.best_distance.max(run.distance);
+
+        progress.persist();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn track(
+        player: Query<(Entity, &Score, &Distance, &Memory, &AuxiliaryVelocity), With<Primary>>,
+        mut collected: EventReader<Collected>,
+        mut jumped: EventReader<Jumped>,
+        mut landed: EventReader<Landed>,
+        mut unlocked: EventWriter<AchievementUnlocked>,
+        mut run: ResMut<RunProgress>,
+        mut progress: ResMut<AchievementProgress>,
+        data: Res<DataAssets>,
+        achievements: Res<Assets<Achievements>>,
+        time: Res<Time>,
+    ) {
+        let Ok((entity, score, distance, memory, velocity)) = player.get_single() else {
+            return;
+        };
+
+        let Some(achievements) = achievements.get(&data.achievements) else {
+            return;
+        };
+
+        run.score = score.value;
+        run.distance = distance.value;
+        run.bytes += collected
+            .read()
+            .filter(|event| event.player == entity)
+            .count() as u32;
+
+        if jumped.read().any(|event| event.player == entity) {
+            run.jump = Some((run.ground, velocity.value.x));
+        }
+
+        let mut gap = None;
+        for landing in landed.read().filter(|event| event.player == entity) {
+            if let Some((from, speed)) = run.jump.take() {
+                if from.is_some() && from != landing.ground {
+                    gap = Some(speed);
+                }
+            }
+
+            run.ground = landing.ground;
+        }
+
+        let mut changed = false;
+
+        for achievement in achievements.0.iter() {
+            if progress.unlocked.contains(&achievement.id) {
+                continue;
+            }
+
+            let bytes = run.bytes as f32;
+            let value = run.values.entry(achievement.id.clone()).or_default();
+
+            match achievement.goal {
+                Goal::Score(_) => *value = score.value,
+                Goal::BytesInRun(_) => *value = bytes,
+                Goal::LowMemory { memory: under, .. } => {
+                    if memory.value() < under / 100.0 * PLAYER_MAX_MEMORY {
+                        *value += time.delta_seconds();
+                    } else {
+                        *value = 0.0;
+                    }
+                }
+                Goal::GapAtSpeed(fraction) => {
+                    if gap.is_some_and(|speed| speed >= fraction * PLAYER_MAX_VELOCITY_X) {
+                        *value = 1.0;
+                    }
+                }
+            }
+
+            if *value >= achievement.goal.target() {
+                progress.unlocked.insert(achievement.id.clone());
+                progress
+                    .best
+                    .insert(achievement.id.clone(), achievement.goal.target());
+
+                if let Some(unlock) = achievement.unlocks.clone() {
+                    progress.unlocks.insert(unlock);
+                }
+
+                unlocked.send(AchievementUnlocked(achievement.clone()));
+                changed = true;
+            }
+        }
+
+        if changed {
+            progress.persist();
+        }
+    }
+
+    fn toast(
+        mut commands: Commands,
+        mut unlocked: EventReader<AchievementUnlocked>,
+        container: Query<Entity, With<Toasts>>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        let Ok(container) = container.get_single() else {
+            return;
+        };
+
+        for AchievementUnlocked(achievement) in unlocked.read() {
+            let tween = Tween::new(
+                EaseFunction::QuadraticOut,
+                Duration::from_millis(400),
+                UiPositionLens {
+                    start: UiRect::left(Val::Px(600.0)),
+                    end: UiRect::left(Val::Px(0.0)),
+                },
+            );
+
+            let style = |font_size: f32| TextStyle {
+                font: fonts.vcr.clone(),
+                font_size,
+                color: Color::WHITE,
+            };
+
+            commands.entity(container).with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::all(Val::Px(16.0)),
+                            ..Default::default()
+                        },
+                        background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+                        ..Default::default()
+                    })
+                    .insert(Name::new("Toast"))
+                    .insert(Toast(Timer::new(
+                        ACHIEVEMENT_TOAST_DURATION,
+                        TimerMode::Once,
+                    )))
+                    .insert(Animator::new(tween))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            localization.get("achievement-unlocked"),
+                            style(20.0),
+                        ));
+                        parent.spawn(TextBundle::from_section(
+                            localization.get(&achievement.name()),
+                            style(38.0),
+                        ));
+                        parent.spawn(TextBundle::from_section(
+                            localization.get(&achievement.description()),
+                            style(20.0),
+                        ));
+                    });
+            });
+        }
+    }
+
+    fn dismiss(mut commands: Commands, mut toasts: Query<(Entity, &mut Toast)>, time: Res<Time>) {
+        for (entity, mut toast) in toasts.iter_mut() {
+            if toast.0.tick(time.delta()).finished() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
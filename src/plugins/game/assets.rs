@@ -1,3 +1,12 @@
+use super::{
+    achievements::Achievements,
+    data::{reload_json, JsonAssetPlugin},
+    locale::LocaleAssets,
+    milestones::Milestones,
+    narrative::DialogScript,
+};
+use crate::plugins::debug::{CommandResult, ConsoleApp};
+use crate::plugins::entities::player::Skins;
 use crate::GameAssetsState;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
@@ -7,18 +16,55 @@ pub struct GameAssetsPlugin;
 
 impl Plugin for GameAssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_state::<GameAssetsState>().add_loading_state(
-            LoadingState::new(GameAssetsState::Pending)
-                .continue_to_state(GameAssetsState::Loaded)
-                .load_collection::<SpriteLayouts>()
-                .load_collection::<TextureAssets>()
-                .load_collection::<FontsAssets>()
-                .load_collection::<AudioAssets>(),
-        );
+        app.add_plugins(JsonAssetPlugin::<Achievements>::new(&["achievements.json"]))
+            .add_plugins(JsonAssetPlugin::<Skins>::new(&["skins.json"]))
+            .add_plugins(JsonAssetPlugin::<DialogScript>::new(&["dialog.json"]))
+            .add_plugins(JsonAssetPlugin::<Milestones>::new(&["milestones.json"]))
+            .init_state::<GameAssetsState>()
+            .add_loading_state(
+                LoadingState::new(GameAssetsState::Pending)
+                    .continue_to_state(GameAssetsState::Loaded)
+                    .load_collection::<SpriteLayouts>()
+                    .load_collection::<TextureAssets>()
+                    .load_collection::<FontsAssets>()
+                    .load_collection::<AudioAssets>()
+                    .load_collection::<DataAssets>()
+                    .load_collection::<LocaleAssets>(),
+            )
+            .console_command("tuning", "tuning reload", &["reload"], Self::tuning_command);
     }
 }
 
-#[derive(AssetCollection, Resource)]
+impl GameAssetsPlugin {
+    /// Picks up edits to the json data without restarting, the files are read from the
+    /// source tree instead of the copies embedded in the binary.
+    fn tuning_command(
+        In(args): In<Vec<String>>,
+        data: Option<Res<DataAssets>>,
+        mut achievements: ResMut<Assets<Achievements>>,
+        mut skins: ResMut<Assets<Skins>>,
+        mut dialog: ResMut<Assets<DialogScript>>,
+        mut milestones: ResMut<Assets<Milestones>>,
+    ) -> CommandResult {
+        if args.first().map(String::as_str) != Some("reload") {
+            return Err("usage: tuning reload".into());
+        }
+
+        let data = data.ok_or("assets are still loading")?;
+        let failed = |file: &'static str| move |err| format!("{file}: {err}");
+
+        reload_json(&mut achievements, &data.achievements, "achievements.json")
+            .map_err(failed("achievements.json"))?;
+        reload_json(&mut skins, &data.skins, "skins.json").map_err(failed("skins.json"))?;
+        reload_json(&mut dialog, &data.dialog, "dialog.json").map_err(failed("dialog.json"))?;
+        reload_json(&mut milestones, &data.milestones, "milestones.json")
+            .map_err(failed("milestones.json"))?;
+
+        Ok("achievements, skins, dialog and milestones reloaded".into())
+    }
+}
+
+#[derive(AssetCollection, Resource, Default)]
 pub struct TextureAssets {
     #[asset(path = "embedded://background/background_scaled_0002_buildings_0.png")]
     pub bg_buildings_0: Handle<Image>,
@@ -48,7 +94,7 @@ pub struct TextureAssets {
     pub byte: Handle<Image>,
 }
 
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct SpriteLayouts {
     #[asset(texture_atlas_layout(tile_size_x = 48.0, tile_size_y = 48.9, columns = 10, rows = 4))]
     pub player_layout: Handle<TextureAtlasLayout>,
@@ -62,6 +108,9 @@ pub struct SpriteLayouts {
 pub struct FontsAssets {
     #[asset(path = "embedded://fonts/VCR_OSD_MONO.ttf")]
     pub vcr: Handle<Font>,
+    /// covers the scripts the pixel font doesn't
+    #[asset(path = "embedded://fonts/DejaVuSansMono.ttf")]
+    pub fallback: Handle<Font>,
 }
 
 #[derive(AssetCollection, Resource)]
@@ -74,4 +123,30 @@ pub struct AudioAssets {
     pub jmup: Handle<AudioSource>,
     #[asset(path = "embedded://audio/death.ogg")]
     pub death: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/land.wav")]
+    pub land: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/pickup.wav")]
+    pub pickup: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/beep.wav")]
+    pub beep: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/milestone.wav")]
+    pub milestone: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/sting.wav")]
+    pub sting: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/select.wav")]
+    pub select: Handle<AudioSource>,
+    #[asset(path = "embedded://audio/sfx/blip.wav")]
+    pub blip: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct DataAssets {
+    #[asset(path = "embedded://achievements.json")]
+    pub achievements: Handle<Achievements>,
+    #[asset(path = "embedded://skins.json")]
+    pub skins: Handle<Skins>,
+    #[asset(path = "embedded://dialog.json")]
+    pub dialog: Handle<DialogScript>,
+    #[asset(path = "embedded://milestones.json")]
+    pub milestones: Handle<Milestones>,
 }
@@ -0,0 +1,564 @@
+use std::collections::HashMap;
+
+use bevy::{input::InputSystem, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    plugins::{
+        debug::{CommandResult, ConsoleApp},
+        entities::{
+            player::{
+                AuxiliaryVelocity, Being, Controls, MovementType, Player, PlayerGrounded,
+                PlayerPlugin,
+            },
+            terrain::Platform,
+        },
+    },
+    GameState, Ground, Grounded, Interpolated,
+};
+use glib::*;
+
+/// Where a jump comes down, worked out from the same numbers `PlayerPlugin::jump`
+/// applies to the runner.
+#[derive(Debug, Clone, Copy)]
+pub struct JumpArc {
+    /// horizontal speed of the runner, held for the whole jump
+    pub speed: f32,
+    /// pull of the world, positive
+    pub gravity: f32,
+}
+
+impl JumpArc {
+    /// Upward speed the jump impulse gives.
+    pub fn launch(&self) -> f32 {
+        (PLAYER_JUMP_HEIGHT * 2.0 * self.gravity / PLAYER_FALL_GRAVITY).sqrt()
+    }
+
+    /// Seconds it takes to reach the top of a held jump.
+    pub fn apex(&self) -> f32 {
+        self.launch() / (self.gravity * PLAYER_RISE_GRAVITY)
+    }
+
+    /// Distance covered once the feet come back down to `rise` above the takeoff, with
+    /// the key held for `hold` seconds. `None` when the jump never gets that high.
+    pub fn reach(&self, hold: f32, rise: f32) -> Option<f32> {
+        let launch = self.launch();
+        let rising = self.gravity * PLAYER_RISE_GRAVITY;
+
+        // letting go past the top of the jump changes nothing
+        let hold = hold.min(self.apex());
+        let height = launch * hold - rising * hold * hold / 2.0;
+        let mut velocity = launch - rising * hold;
+
+        if hold < PLAYER_JUMP_WINDOW {
+            velocity -= (-0.9f32).exp() * launch;
+        }
+
+        // whatever is left of the way up goes at the rise gravity, the way down is
+        // always at the fall gravity
+        let up = velocity.max(0.0);
+        let top = height + up * up / (2.0 * rising);
+
+        if top < rise {
+            return None;
+        }
+
+        Self::fall(
+            velocity.min(0.0),
+            self.gravity * PLAYER_FALL_GRAVITY,
+            rise - top,
+        )
+        .map(|time| (hold + up / rising + time) * self.speed)
+    }
+
+    /// How long to hold the key for to land the closest to `distance` ahead, along
+    /// with where that jump lands.
+    pub fn hold_for(&self, distance: f32, rise: f32) -> Option<(f32, f32)> {
+        let ticks = (self.apex() / SIMULATION_TIMESTEP).ceil() as usize;
+
+        // the last one is held past the top, as far as the jump goes
+        (0..=ticks)
+            .map(|tick| tick as f32 * SIMULATION_TIMESTEP)
+            .filter_map(|hold| self.reach(hold, rise).map(|reach| (hold, reach)))
+            .min_by(|(_, a), (_, b)| (a - distance).abs().total_cmp(&(b - distance).abs()))
+    }
+
+    /// Time a body going up at `velocity` takes to come down to `rise`.
+    fn fall(velocity: f32, gravity: f32, rise: f32) -> Option<f32> {
+        let discriminant = velocity * velocity - 2.0 * gravity * rise;
+        (discriminant >= 0.0).then(|| (velocity + discriminant.sqrt()) / gravity)
+    }
+}
+
+/// Plays in place of the humans, through the keys they would press.
+#[derive(Resource, Default, Debug)]
+pub struct Bot {
+    pub enabled: bool,
+    /// platforms no jump could land on, counted as they come up
+    pub unreachable: u32,
+}
+
+/// The jump a runner is in the middle of.
+#[derive(Default)]
+struct Plan {
+    /// platform jumped off, only one jump is taken from each
+    from: Option<Entity>,
+    /// seconds left before letting go of the key
+    hold: f32,
+}
+
+pub struct GameBotPlugin;
+
+impl Plugin for GameBotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Bot>()
+            .add_systems(
+                PreUpdate,
+                (
+                    Self::drive
+                        .run_if(in_state(MovementType::Running))
+                        .run_if(in_state(Being::Alive)),
+                    Self::restart.run_if(in_state(Being::Dead)),
+                )
+                    .after(InputSystem)
+                    .before(PlayerPlugin::read_controls)
+                    .run_if(|bot: Res<Bot>| bot.enabled)
+                    .run_if(in_state(GameState::Resumed)),
+            )
+            .console_command("bot", "bot <on|off>", &["on", "off"], Self::bot_command);
+    }
+}
+
+impl GameBotPlugin {
+    /// Takes off right before the edge of a platform and holds the key for as long as
+    /// it takes to land a bit into the next one.
+    #[allow(clippy::too_many_arguments)]
+    fn drive(
+        mut bot: ResMut<Bot>,
+        player: Query<(Entity, &Controls, &Interpolated, &AuxiliaryVelocity), With<Player>>,
+        grounded: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        grounds: Query<&Parent, With<Ground>>,
+        platforms: Query<(Entity, &Platform)>,
+        mut keys: ResMut<ButtonInput<KeyCode>>,
+        rules: Res<RapierConfiguration>,
+        time: Res<Time>,
+        mut plans: Local<HashMap<Entity, Plan>>,
+    ) {
+        plans.retain(|entity, _| player.contains(*entity));
+
+        for (entity, controls, interpolated, velocity) in player.iter() {
+            let plan = plans.entry(entity).or_default();
+
+            if keys.pressed(controls.jump) {
+                plan.hold -= time.delta_seconds();
+
+                if plan.hold <= 0.0 {
+                    keys.release(controls.jump);
+                }
+                continue;
+            }
+
+            let Some(ground) = PlayerGrounded::of(&grounded, entity)
+                .filter(|grounded| grounded.value)
+                .and_then(|grounded| grounded.ground)
+            else {
+                continue;
+            };
+
+            let Some((from, platform)) = grounds
+                .get(ground)
+                .ok()
+                .and_then(|parent| platforms.get(parent.get()).ok())
+            else {
+                continue;
+            };
+
+            if plan.from == Some(from) {
+                continue;
+            }
+
+            let Some((_, next)) = platforms
+                .iter()
+                .filter(|(_, next)| next.left() >= platform.right())
+                .min_by(|(_, a), (_, b)| a.left().total_cmp(&b.left()))
+            else {
+                continue;
+            };
+
+            let x = interpolated.current.x;
+            let speed = PLAYER_VELOCITY_SCALE * velocity.value.x;
+
+            if platform.right() - x > speed * BOT_JUMP_LEAD {
+                continue;
+            }
+
+            let arc = JumpArc {
+                speed,
+                gravity: -rules.gravity.y,
+            };
+            let rise = next.top() - platform.top();
+            let margin = PLAYER_COLLIDER_WIDTH / 2.0 * PLAYER_SCALE_X;
+            let aim = (next.left() + next.width * BOT_LANDING_AIM)
+                .max(next.left() + margin)
+                .min(next.right() - margin);
+
+            let hold = match arc.hold_for(aim - x, rise) {
+                Some((hold, reach)) if (next.left()..=next.right()).contains(&(x + reach)) => hold,
+                landing => {
+                    bot.unreachable += 1;
+                    let gap = next.left() - platform.right();
+                    let miss = landing.map(|(_, reach)| x + reach - next.left());
+                    warn!(
+                        "no jump lands {gap:.0} ahead and {rise:.0} up, closest misses by {miss:?}"
+                    );
+                    arc.apex()
+                }
+            };
+
+            keys.press(controls.jump);
+            *plan = Plan {
+                from: Some(from),
+                hold,
+            };
+        }
+    }
+
+    /// Taps the restart key once the run is over so soak runs go on unattended.
+    fn restart(mut keys: ResMut<ButtonInput<KeyCode>>) {
+        match keys.pressed(KeyCode::KeyR) {
+            true => keys.release(KeyCode::KeyR),
+            false => keys.press(KeyCode::KeyR),
+        }
+    }
+
+    fn bot_command(In(args): In<Vec<String>>, mut bot: ResMut<Bot>) -> CommandResult {
+        bot.enabled = match args.first().map(String::as_str) {
+            Some("on") => true,
+            Some("off") => false,
+            _ => return Err("usage: bot <on|off>".into()),
+        };
+
+        Ok(match bot.enabled {
+            true => format!(
+                "bot playing, {} unreachable platforms so far",
+                bot.unreachable
+            ),
+            false => "bot stopped".into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_bot {
+    use std::time::Duration;
+
+    use bevy::{
+        ecs::event::ManualEventReader, input::InputPlugin, scene::ScenePlugin,
+        time::TimeUpdateStrategy,
+    };
+
+    use super::*;
+    use crate::{
+        plugins::entities::{
+            bytes::Byte,
+            player::{DeathCause, Died, Distance, JumpInput, Jumped, Landed, Runner},
+            terrain::{
+                Building, BuildingsPlugin, FloatingOriginPlugin, Pool, ScrollSpeed, WorldRng,
+            },
+        },
+        Difficulty, GameGroundCheckPlugin, GameTimestepPlugin, GameplaySet, Interpolated,
+        SpriteLayouts, TextureAssets,
+    };
+
+    const GRAVITY: f32 = 981.0;
+
+    #[test]
+    fn holding_longer_jumps_further() {
+        let arc = JumpArc {
+            speed: 600.0,
+            gravity: GRAVITY,
+        };
+        let top = arc.launch().powi(2) / (2.0 * GRAVITY);
+        let descent = (2.0 * top / (GRAVITY * PLAYER_FALL_GRAVITY)).sqrt();
+        let held = arc.reach(f32::INFINITY, 0.0).unwrap();
+
+        // up at the rise gravity, down at the fall gravity
+        assert!((held - (arc.apex() + descent) * arc.speed).abs() < 0.01);
+        assert!(arc.reach(0.0, 0.0) < arc.reach(0.2, 0.0));
+        assert!(arc.reach(0.2, 0.0) < arc.reach(f32::INFINITY, 0.0));
+        // a held jump tops out at `PLAYER_JUMP_HEIGHT / PLAYER_FALL_GRAVITY`
+        assert!(arc
+            .reach(f32::INFINITY, PLAYER_JUMP_HEIGHT / 2.0 + 1.0)
+            .is_none());
+
+        let (hold, reach) = arc.hold_for(420.0, -100.0).unwrap();
+        assert!(hold < PLAYER_JUMP_WINDOW);
+        assert!((reach - 420.0).abs() < arc.speed * SIMULATION_TIMESTEP * 2.0);
+    }
+
+    struct Outcome {
+        /// `Distance` of the runner, it keeps counting across origin shifts
+        travelled: f32,
+        unreachable: u32,
+        /// never missed a jump, memory is not part of it
+        survived: bool,
+    }
+
+    /// A headless world at `difficulty`, the bot plays when `bot` is set. Memory and
+    /// bytes are left out, only the jumps are put to the test. The origin shifts back
+    /// as it does in a run, long soaks keep the same precision as the game.
+    fn world(seed: u64, difficulty: f32, bot: bool) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            HierarchyPlugin,
+            AssetPlugin::default(),
+            ScenePlugin,
+            InputPlugin,
+            GameTimestepPlugin,
+        ))
+        .init_asset::<Mesh>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            SIMULATION_TIMESTEP,
+        )))
+        .insert_resource(RapierConfiguration {
+            timestep_mode: TimestepMode::Fixed {
+                dt: SIMULATION_TIMESTEP,
+                substeps: 1,
+            },
+            ..RapierConfiguration::new(100.0)
+        })
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0).in_fixed_schedule())
+        .add_plugins(GameGroundCheckPlugin::<Ground>::default())
+        .add_plugins(GameBotPlugin)
+        .insert_state(GameState::Resumed)
+        .init_state::<MovementType>()
+        .init_state::<Being>()
+        .insert_resource(Bot {
+            enabled: bot,
+            ..Default::default()
+        })
+        .insert_resource(WorldRng::seeded(seed))
+        .insert_resource(Difficulty(difficulty))
+        .init_resource::<ScrollSpeed>()
+        .init_resource::<TextureAssets>()
+        .init_resource::<SpriteLayouts>()
+        .init_resource::<Pool<Building>>()
+        .init_resource::<Pool<Byte>>()
+        .add_systems(
+            Startup,
+            (BuildingsPlugin::setup, |mut commands: Commands| {
+                PlayerPlugin::spawn(
+                    &mut commands,
+                    &TextureAssets::default(),
+                    &SpriteLayouts::default(),
+                    0,
+                );
+            }),
+        )
+        .add_systems(
+            FixedUpdate,
+            (BuildingsPlugin::generate, FloatingOriginPlugin::rebase)
+                .in_set(GameplaySet)
+                .run_if(in_state(Being::Alive)),
+        )
+        .add_systems(Update, BuildingsPlugin::despawn);
+        PlayerPlugin::simulation(&mut app);
+
+        app
+    }
+
+    #[test]
+    fn reach_matches_a_simulated_jump() {
+        let mut app = world(0, 1.0, false);
+        let mut jumped = ManualEventReader::<Jumped>::default();
+        let mut landed = ManualEventReader::<Landed>::default();
+
+        // walk up to a run on the first platform, it is long enough to land back on
+        while *app.world.resource::<State<MovementType>>().get() != MovementType::Running {
+            app.update();
+        }
+        app.update();
+
+        let mut runner = app
+            .world
+            .query_filtered::<(&mut JumpInput, &Velocity), With<Player>>();
+        runner.single_mut(&mut app.world).0.pressed = true;
+
+        let mut takeoff = None;
+        let mut ticks = 0;
+
+        let (from, to, airtime) = loop {
+            app.update();
+            assert!(ticks < 120, "the jump never came down");
+
+            let speed = runner.single(&app.world).1.linvel.x;
+            let events = app.world.resource::<Events<Jumped>>();
+            if let Some(event) = jumped.read(events).next() {
+                takeoff = Some((event.position.x, speed));
+            }
+
+            let Some(from) = takeoff else {
+                continue;
+            };
+            ticks += 1;
+
+            let events = app.world.resource::<Events<Landed>>();
+            if let Some(event) = landed.read(events).next() {
+                break (
+                    from,
+                    (event.position.x, speed),
+                    ticks as f32 * SIMULATION_TIMESTEP,
+                );
+            }
+        };
+
+        // runners keep speeding up, steadily enough for the mean of both ends to hold
+        let arc = JumpArc {
+            speed: (from.1 + to.1) / 2.0,
+            gravity: GRAVITY,
+        };
+        let reach = arc.reach(f32::INFINITY, 0.0).unwrap();
+
+        assert!(
+            (to.0 - from.0 - reach).abs() < arc.speed * SIMULATION_TIMESTEP * 2.0,
+            "jumped {:.1} in {airtime:.3}s, reach {reach:.1} in {:.3}s",
+            to.0 - from.0,
+            reach / arc.speed,
+        );
+    }
+
+    #[test]
+    fn the_run_goes_on_without_a_runner_left_behind() {
+        let mut app = world(0, 1.0, false);
+        let mut died = ManualEventReader::<Died>::default();
+
+        // the second runner is held at the start while the first one runs off
+        app.add_systems(Startup, |mut commands: Commands| {
+            PlayerPlugin::spawn(
+                &mut commands,
+                &TextureAssets::default(),
+                &SpriteLayouts::default(),
+                1,
+            );
+        })
+        .add_systems(
+            FixedUpdate,
+            (|mut runners: Query<(&Runner, &mut Transform)>| {
+                for (runner, mut transform) in runners.iter_mut() {
+                    if runner.0 == 1 {
+                        transform.translation.x = PLAYER_SPAWN_X - VERSUS_SPAWN_SPACING;
+                    }
+                }
+            })
+            .before(GameplaySet),
+        );
+
+        let mut deaths = Vec::new();
+        for _ in 0..600 {
+            app.update();
+            let events = app.world.resource::<Events<Died>>();
+            deaths.extend(died.read(events).map(|death| (death.runner, death.cause)));
+
+            if !deaths.is_empty() {
+                break;
+            }
+        }
+
+        assert_eq!(deaths, [(1, DeathCause::LeftBehind)]);
+
+        for _ in 0..60 {
+            app.update();
+        }
+
+        let mut leader = app
+            .world
+            .query_filtered::<(&Runner, &mut Transform, &mut Interpolated), With<Player>>();
+        assert_eq!(
+            *app.world.resource::<State<Being>>().get(),
+            Being::Alive,
+            "the run ended with a runner still going"
+        );
+        assert_eq!(
+            leader
+                .iter(&app.world)
+                .map(|(runner, ..)| runner.0)
+                .collect::<Vec<_>>(),
+            [0]
+        );
+
+        // the last one out ends the run
+        let (_, mut transform, mut interpolated) = leader.single_mut(&mut app.world);
+        transform.translation.y = PLATFORMS_MIN_Y - 100.0;
+        *interpolated = Interpolated::at(transform.translation);
+
+        app.update();
+        app.update();
+
+        assert_eq!(*app.world.resource::<State<Being>>().get(), Being::Dead);
+    }
+
+    /// Runs a headless world at `difficulty` until the bot falls or `ticks` go by. It
+    /// checks every platform the generator lays out can be jumped to, not how long a
+    /// run lasts: the runner never drains memory and never picks up bytes.
+    fn soak(seed: u64, difficulty: f32, ticks: usize) -> Outcome {
+        let mut app = world(seed, difficulty, true);
+
+        let mut travelled = 0.0;
+        let mut survived = true;
+
+        for _ in 0..ticks {
+            app.update();
+
+            let being = app.world.resource::<State<Being>>();
+            if *being.get() == Being::Dead {
+                survived = false;
+                break;
+            }
+
+            if let Some(distance) = app
+                .world
+                .query_filtered::<&Distance, With<Player>>()
+                .iter(&app.world)
+                .next()
+            {
+                travelled = distance.value;
+            }
+        }
+
+        Outcome {
+            travelled,
+            unreachable: app.world.resource::<Bot>().unreachable,
+            survived,
+        }
+    }
+
+    /// `cargo test --release soak_bot -- --ignored --nocapture`, `BOT_RUNS` sets how
+    /// many runs each difficulty gets.
+    #[test]
+    #[ignore]
+    fn soak_bot() {
+        const TICKS: usize = 60 * 60 * 3;
+
+        let runs = std::env::var("BOT_RUNS")
+            .ok()
+            .and_then(|runs| runs.parse().ok())
+            .unwrap_or(20);
+
+        for difficulty in [1.0, 1.25, 1.5, 2.0] {
+            let outcomes = (0..runs)
+                .map(|seed| soak(seed, difficulty, TICKS))
+                .collect::<Vec<_>>();
+
+            let mean = outcomes.iter().map(|run| run.travelled).sum::<f32>() / runs as f32;
+            let unreachable = outcomes.iter().map(|run| run.unreachable).sum::<u32>();
+            let survived = outcomes.iter().filter(|run| run.survived).count();
+
+            println!(
+                "difficulty {difficulty}: {mean:.0} travelled on average, {survived}/{runs} runs \
+                 without a missed jump, {unreachable} unreachable platforms"
+            );
+        }
+    }
+}
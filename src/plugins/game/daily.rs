@@ -0,0 +1,402 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use fluent_bundle::FluentArgs;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    plugins::entities::{
+        player::{Being, Memory, Player, ScoreBreakdown},
+        terrain::WorldRng,
+    },
+    FontsAssets, GameAssetsState, GameState, Localization,
+};
+use glib::*;
+
+const RECORDS_FILE: &str = "daily.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DailyModifier {
+    LowGravity,
+    FasterDrain,
+    NoBytes,
+    /// the camera sits ahead of the player instead of behind
+    MirroredCamera,
+}
+
+impl DailyModifier {
+    pub const ALL: [DailyModifier; 4] = [
+        DailyModifier::LowGravity,
+        DailyModifier::FasterDrain,
+        DailyModifier::NoBytes,
+        DailyModifier::MirroredCamera,
+    ];
+
+    /// Key of the name in the string tables.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::LowGravity => "daily-low-gravity",
+            Self::FasterDrain => "daily-faster-drain",
+            Self::NoBytes => "daily-no-bytes",
+            Self::MirroredCamera => "daily-mirrored-camera",
+        }
+    }
+}
+
+/// Today's challenge, the same world and modifiers for everyone playing on that day.
+#[derive(Resource, Debug, Clone)]
+pub struct DailyChallenge {
+    /// days since the unix epoch, in utc
+    pub day: u64,
+    pub seed: u64,
+    pub modifiers: Vec<DailyModifier>,
+    /// whether the current run is a daily one
+    pub active: bool,
+    /// whether the current run is the one attempt of the day that gets recorded
+    pub scored: bool,
+}
+
+impl Default for DailyChallenge {
+    fn default() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+
+        Self::new(secs / 86_400)
+    }
+}
+
+impl DailyChallenge {
+    pub fn new(day: u64) -> Self {
+        let seed = Self::mix(day);
+
+        // one modifier rotates with the day, a second one is picked by the seed
+        let first = DailyModifier::ALL[(day % 4) as usize];
+        let second = DailyModifier::ALL[(seed % 4) as usize];
+
+        let mut modifiers = vec![first];
+        if second != first {
+            modifiers.push(second);
+        }
+
+        Self {
+            day,
+            seed,
+            modifiers,
+            active: false,
+            scored: false,
+        }
+    }
+
+    /// splitmix64, spreads consecutive days over the whole seed range
+    fn mix(day: u64) -> u64 {
+        let mut z = day.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Whether `modifier` applies to the current run.
+    pub fn has(&self, modifier: DailyModifier) -> bool {
+        self.active && self.modifiers.contains(&modifier)
+    }
+
+    /// The day as `yyyy-mm-dd`.
+    pub fn date(&self) -> String {
+        // days to civil date, from Howard Hinnant's date algorithms
+        let z = self.day as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + (month <= 2) as i64;
+
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct DailyEntry {
+    pub score: f32,
+}
+
+/// The scored attempt of every day played, kept on disk apart from the achievements.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct DailyRecords {
+    pub days: BTreeMap<u64, DailyEntry>,
+}
+
+impl DailyRecords {
+    fn path() -> PathBuf {
+        utils::data_dir().join(RECORDS_FILE)
+    }
+
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = Self::path();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn played(&self, day: u64) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    /// Best days first.
+    pub fn leaderboard(&self) -> Vec<(u64, DailyEntry)> {
+        let mut entries = self
+            .days
+            .iter()
+            .map(|(day, entry)| (*day, *entry))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(_, a), (_, b)| b.score.total_cmp(&a.score));
+        entries.truncate(DAILY_LEADERBOARD_SIZE);
+        entries
+    }
+}
+
+#[derive(Component)]
+struct DailyPanel;
+
+pub struct GameDailyPlugin;
+
+impl Plugin for GameDailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DailyChallenge>()
+            .insert_resource(DailyRecords::load())
+            .add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
+            .add_systems(
+                OnEnter(GameState::Resumed),
+                (Self::close, Self::seed, Self::claim),
+            )
+            .add_systems(OnEnter(Being::Alive), Self::seed)
+            .add_systems(OnEnter(Being::Dead), Self::record)
+            .add_systems(
+                Update,
+                (
+                    Self::label.run_if(in_state(GameState::Paused)),
+                    Self::gravity.run_if(resource_changed::<DailyChallenge>),
+                    Self::drain,
+                )
+                    .run_if(in_state(GameAssetsState::Loaded)),
+            );
+    }
+}
+
+impl GameDailyPlugin {
+    fn setup(mut commands: Commands, fonts: Res<FontsAssets>) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(20.0),
+                    right: Val::Px(20.0),
+                    ..Default::default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+                ..Default::default()
+            })
+            .insert(Name::new("Daily Challenge"))
+            .with_children(|parent| {
+                let style = TextStyle {
+                    font: fonts.vcr.clone(),
+                    font_size: 18.0,
+                    color: Color::WHITE,
+                };
+
+                parent
+                    .spawn(TextBundle::from_section("", style).with_style(Style {
+                        margin: UiRect::all(Val::Px(12.0)),
+                        ..Default::default()
+                    }))
+                    .insert(DailyPanel);
+            });
+    }
+
+    fn close(mut commands: Commands, panel: Query<&Parent, With<DailyPanel>>) {
+        for parent in panel.iter() {
+            commands.entity(parent.get()).despawn_recursive();
+        }
+    }
+
+    /// Lays the world out from the day's seed, every other run gets a fresh one. The
+    /// first run is already set up behind the title screen so it gets reseeded once
+    /// the mode is picked.
+    fn seed(mut commands: Commands, challenge: Res<DailyChallenge>) {
+        let rng = match challenge.active {
+            true => WorldRng::seeded(challenge.seed),
+            false => WorldRng::default(),
+        };
+
+        commands.insert_resource(rng);
+    }
+
+    /// The attempt of the day is used up as soon as the scored run starts, quitting
+    /// halfway through doesn't give another one.
+    fn claim(challenge: Res<DailyChallenge>, mut records: ResMut<DailyRecords>) {
+        if !challenge.active || !challenge.scored || records.played(challenge.day) {
+            return;
+        }
+
+        records
+            .days
+            .insert(challenge.day, DailyEntry { score: 0.0 });
+
+        if let Err(err) = records.save() {
+            warn!("failed to save the daily challenge: {err}");
+        }
+    }
+
+    /// Only the first run of the day counts, the following ones are practice.
+    fn record(
+        mut challenge: ResMut<DailyChallenge>,
+        mut records: ResMut<DailyRecords>,
+        breakdown: Res<ScoreBreakdown>,
+    ) {
+        if !challenge.active || !challenge.scored {
+            return;
+        }
+
+        challenge.scored = false;
+        records.days.insert(
+            challenge.day,
+            DailyEntry {
+                score: breakdown.total(),
+            },
+        );
+
+        if let Err(err) = records.save() {
+            warn!("failed to save the daily challenge: {err}");
+        }
+    }
+
+    fn gravity(
+        challenge: Res<DailyChallenge>,
+        mut rapier: ResMut<RapierConfiguration>,
+        mut normal: Local<Option<Vect>>,
+    ) {
+        let normal = *normal.get_or_insert(rapier.gravity);
+
+        rapier.gravity = match challenge.has(DailyModifier::LowGravity) {
+            true => normal * DAILY_LOW_GRAVITY,
+            false => normal,
+        };
+    }
+
+    fn drain(challenge: Res<DailyChallenge>, mut player: Query<(Ref<Player>, &mut Memory)>) {
+        let factor = match challenge.has(DailyModifier::FasterDrain) {
+            true => DAILY_FASTER_DRAIN,
+            false => 1.0,
+        };
+
+        for (player, mut memory) in player.iter_mut() {
+            if player.is_added() || challenge.is_changed() {
+                memory.drain = PLAYER_MEMORY_DRAINING_RATE * factor;
+            }
+        }
+    }
+
+    fn label(
+        challenge: Res<DailyChallenge>,
+        records: Res<DailyRecords>,
+        localization: Res<Localization>,
+        mut panel: Query<&mut Text, With<DailyPanel>>,
+    ) {
+        if !challenge.is_changed() && !records.is_changed() && !localization.is_changed() {
+            return;
+        }
+
+        let Ok(mut text) = panel.get_single_mut() else {
+            return;
+        };
+
+        let modifiers = challenge
+            .modifiers
+            .iter()
+            .map(|modifier| localization.get(modifier.key()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut args = FluentArgs::new();
+        args.set("date", challenge.date());
+        args.set("modifiers", modifiers);
+
+        let mut lines = vec![
+            localization.format("daily-title", Some(&args)),
+            localization.format("daily-modifiers", Some(&args)),
+            match records.played(challenge.day) {
+                true => localization.get("daily-played"),
+                false => localization.get("daily-start"),
+            },
+        ];
+
+        let leaderboard = records.leaderboard();
+        if !leaderboard.is_empty() {
+            lines.push(localization.get("daily-leaderboard"));
+        }
+
+        for (rank, (day, entry)) in leaderboard.into_iter().enumerate() {
+            let mut args = FluentArgs::new();
+            args.set("rank", rank + 1);
+            args.set("date", DailyChallenge::new(day).date());
+            args.set("score", entry.score.round());
+            lines.push(localization.format("daily-entry", Some(&args)));
+        }
+
+        text.sections[0].value = lines.join("\n");
+    }
+}
+
+#[cfg(test)]
+mod test_daily {
+    use super::{DailyChallenge, DailyModifier};
+
+    #[test]
+    fn dates_follow_the_calendar() {
+        assert_eq!(DailyChallenge::new(0).date(), "1970-01-01");
+        assert_eq!(DailyChallenge::new(11_016).date(), "2000-02-29");
+        assert_eq!(DailyChallenge::new(20_745).date(), "2026-10-19");
+    }
+
+    #[test]
+    fn the_same_day_gets_the_same_challenge() {
+        let (a, b) = (DailyChallenge::new(20_000), DailyChallenge::new(20_000));
+        assert_eq!(a.seed, b.seed);
+        assert_eq!(a.modifiers, b.modifiers);
+        assert_ne!(a.seed, DailyChallenge::new(20_001).seed);
+    }
+
+    #[test]
+    fn modifiers_only_apply_to_daily_runs() {
+        let mut challenge = DailyChallenge::new(20_000);
+        let modifier = challenge.modifiers[0];
+        assert!(!challenge.has(modifier));
+
+        challenge.active = true;
+        assert!(challenge.has(modifier));
+        assert!(DailyModifier::ALL
+            .iter()
+            .filter(|m| !challenge.modifiers.contains(m))
+            .all(|m| !challenge.has(*m)));
+    }
+}
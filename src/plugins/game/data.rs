@@ -0,0 +1,116 @@
+use std::{fmt, marker::PhantomData};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::BoxedFuture,
+};
+use serde::de::DeserializeOwned;
+
+#[derive(Debug)]
+pub enum JsonAssetError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for JsonAssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read asset: {err}"),
+            Self::Json(err) => write!(f, "could not parse asset: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for JsonAssetError {}
+
+impl From<std::io::Error> for JsonAssetError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for JsonAssetError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+struct JsonAssetLoader<A> {
+    extensions: &'static [&'static str],
+    marker: PhantomData<A>,
+}
+
+impl<A> AssetLoader for JsonAssetLoader<A>
+where
+    A: Asset + DeserializeOwned,
+{
+    type Asset = A;
+    type Settings = ();
+    type Error = JsonAssetError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(serde_json::from_slice(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
+
+/// Registers `A` as an asset read from json files ending with one of `extensions`, e.g.
+/// `achievements.json`.
+pub struct JsonAssetPlugin<A> {
+    extensions: &'static [&'static str],
+    marker: PhantomData<A>,
+}
+
+impl<A> JsonAssetPlugin<A> {
+    pub fn new(extensions: &'static [&'static str]) -> Self {
+        Self {
+            extensions,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A> Plugin for JsonAssetPlugin<A>
+where
+    A: Asset + DeserializeOwned,
+{
+    fn build(&self, app: &mut App) {
+        app.init_asset::<A>()
+            .register_asset_loader(JsonAssetLoader::<A> {
+                extensions: self.extensions,
+                marker: PhantomData,
+            });
+    }
+}
+
+/// Reads `file` again straight from the source tree's `assets` folder and swaps it in
+/// for the embedded copy behind `handle`.
+pub fn reload_json<A>(
+    assets: &mut Assets<A>,
+    handle: &Handle<A>,
+    file: &str,
+) -> Result<(), JsonAssetError>
+where
+    A: Asset + DeserializeOwned,
+{
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("assets")
+        .join(file);
+    let asset = serde_json::from_slice(&std::fs::read(path)?)?;
+
+    assets.insert(handle, asset);
+    Ok(())
+}
@@ -1,75 +1,171 @@
 use std::time::Duration;
 
 use crate::plugins::debug::*;
-use crate::{GameAssetsState, GameState};
+use crate::{FontsAssets, GameAssetsState, GameState};
 use bevy::prelude::*;
+use glib::*;
 
+/// The text box at the bottom of the screen, it stays around and shows whatever was
+/// last handed to `Dialog::play`.
 #[derive(Component, Default, Reflect, Clone, Debug, InspectorOptions)]
 pub struct Dialog {
     pub index: usize,
     pub lines: Vec<Line>,
+    /// how many characters of the current line are revealed so far
+    pub revealed: f32,
 }
 
 impl Dialog {
     pub fn new(lines: Vec<Line>) -> Self {
-        Self { index: 0, lines }
+        Self {
+            index: 0,
+            lines,
+            revealed: 0.0,
+        }
+    }
+
+    /// Drops whatever is being said and starts over with `lines`.
+    pub fn play(&mut self, lines: Vec<Line>) {
+        *self = Self::new(lines);
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.index < self.lines.len()
     }
 }
 
 #[derive(Default, Reflect, Clone, Debug)]
 pub struct Line {
+    speaker: String,
+    color: Color,
+    pitch: f64,
     content: String,
     timer: Timer,
 }
 
 impl Line {
-    pub fn new(content: &'static str, duration: Duration) -> Self {
+    pub fn new(content: impl Into<String>, duration: Duration) -> Self {
         Self {
-            content: String::from(content),
+            speaker: String::new(),
+            color: Color::WHITE,
+            pitch: 1.0,
+            content: content.into(),
             timer: Timer::new(duration, TimerMode::Once),
         }
     }
+
+    /// Prefixes the line with the speaker's name drawn in `color`, `pitch` being the
+    /// playback rate of its blips.
+    pub fn said_by(mut self, speaker: impl Into<String>, color: Color, pitch: f64) -> Self {
+        self.speaker = speaker.into();
+        self.color = color;
+        self.pitch = pitch;
+        self
+    }
+}
+
+/// Sent every few revealed characters so the speaker can be heard talking.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DialogBlip {
+    pub pitch: f64,
 }
 
 pub struct DialogPlugin;
 
 impl Plugin for DialogPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            Self::play_dialogs
-                .run_if(in_state(GameAssetsState::Loaded))
-                .run_if(in_state(GameState::Resumed)),
-        )
-        .register_type::<Line>()
-        .register_type::<Dialog>();
+        app.add_systems(OnEnter(GameAssetsState::Loaded), Self::setup)
+            .add_systems(
+                Update,
+                Self::play_dialogs
+                    .run_if(in_state(GameAssetsState::Loaded))
+                    .run_if(in_state(GameState::Resumed)),
+            )
+            .add_event::<DialogBlip>()
+            .register_type::<Line>()
+            .register_type::<Dialog>();
     }
 }
 
 impl DialogPlugin {
+    fn setup(mut commands: Commands, fonts: Res<FontsAssets>) {
+        let style = |color: Color| TextStyle {
+            font: fonts.vcr.clone(),
+            font_size: 34.0,
+            color,
+        };
+
+        commands
+            .spawn(
+                TextBundle::from_sections([
+                    TextSection::new("", style(Color::WHITE)),
+                    TextSection::new("", style(Color::WHITE)),
+                ])
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    justify_self: JustifySelf::Center,
+                    align_self: AlignSelf::End,
+                    margin: UiRect::bottom(Val::Px(50.0)),
+                    ..Default::default()
+                }),
+            )
+            .insert(Name::new("Dialog"))
+            .insert(Dialog::default());
+    }
+
+    /// Types the current line out and moves to the next one once its timer is done, the
+    /// typing goes on in real time so lines still show up while the game is frozen.
     fn play_dialogs(
-        mut commands: Commands,
-        mut query: Query<(Entity, &mut Dialog, &mut Text)>,
+        mut query: Query<(&mut Dialog, &mut Text)>,
+        mut blips: EventWriter<DialogBlip>,
+        real: Res<Time<Real>>,
         time: Res<Time>,
     ) {
-        if query.is_empty() {
-            return;
-        }
-
-        for (entity, mut dialog, mut text) in query.iter_mut() {
+        for (mut dialog, mut text) in query.iter_mut() {
             let index = dialog.index;
-            let curr_line = &mut dialog.lines[index];
-            let curr_timer = &mut curr_line.timer;
 
-            text.sections[0].value.clone_from(&curr_line.content);
+            let Some(line) = dialog.lines.get(index).cloned() else {
+                if text
+                    .sections
+                    .iter()
+                    .any(|section| !section.value.is_empty())
+                {
+                    text.sections[0].value.clear();
+                    text.sections[1].value.clear();
+                }
+                continue;
+            };
+
+            let before = dialog.revealed as usize;
+            dialog.revealed += DIALOG_TYPEWRITER_SPEED * real.delta_seconds();
+            let after = dialog.revealed as usize;
 
-            if curr_timer.tick(time.delta()).just_finished() {
-                dialog.index =
-                    (index + curr_timer.times_finished_this_tick() as usize) % dialog.lines.len();
+            let content = line.content.chars().take(after).collect::<String>();
+            let typed = line.content.chars().skip(before).take(after - before);
 
-                if index == dialog.lines.len() - 1 {
-                    commands.entity(entity).despawn_recursive();
-                }
+            if typed.enumerate().any(|(offset, c)| {
+                !c.is_whitespace() && (before + offset).is_multiple_of(DIALOG_BLIP_EVERY)
+            }) {
+                blips.send(DialogBlip { pitch: line.pitch });
+            }
+
+            let speaker = match line.speaker.is_empty() {
+                true => String::new(),
+                false => format!("{}: ", line.speaker),
+            };
+
+            if text.sections[0].value != speaker {
+                text.sections[0].value = speaker;
+                text.sections[0].style.color = line.color;
+            }
+
+            if text.sections[1].value != content {
+                text.sections[1].value = content;
+            }
+
+            if dialog.lines[index].timer.tick(time.delta()).finished() {
+                dialog.index += 1;
+                dialog.revealed = 0.0;
             }
         }
     }
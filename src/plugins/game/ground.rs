@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use crate::GameState;
+use crate::{GameState, GameplaySet};
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
@@ -10,11 +10,22 @@ pub struct Ground;
 #[derive(Component, Reflect, PartialEq, Eq)]
 pub struct Grounded {
     pub value: bool,
+    pub ground: Option<Entity>,
 }
 
 impl Grounded {
     pub fn new(value: bool) -> Self {
-        Self { value }
+        Self {
+            value,
+            ground: None,
+        }
+    }
+
+    pub fn on(ground: Entity) -> Self {
+        Self {
+            value: true,
+            ground: Some(ground),
+        }
     }
 }
 
@@ -29,8 +40,10 @@ where
 {
     fn build(&self, app: &mut App) {
         app.add_systems(
-            Update,
-            Self::check_ground.run_if(in_state(GameState::Resumed)),
+            FixedUpdate,
+            Self::check_ground
+                .before(GameplaySet)
+                .run_if(in_state(GameState::Resumed)),
         )
         .register_type::<Grounded>();
     }
@@ -40,7 +53,7 @@ impl<G> GameGroundCheckPlugin<G>
 where
     G: Component,
 {
-    fn check_ground(
+    pub(crate) fn check_ground(
         mut checkers: Query<(Entity, &mut Grounded)>,
         grounds: Query<Entity, With<G>>,
         ctx: Res<RapierContext>,
@@ -49,8 +62,8 @@ where
             for ground in grounds.iter() {
                 let result = ctx.intersection_pair(ground, checker);
                 if result == Some(true) {
-                    *state = Grounded::new(true);
-                    return;
+                    *state = Grounded::on(ground);
+                    break;
                 } else {
                     *state = Grounded::new(false);
                 }
@@ -0,0 +1,558 @@
+use std::{f32::consts::TAU, time::Duration};
+
+use bevy::prelude::*;
+use bevy_tweening::{lens::UiPositionLens, *};
+use fluent_bundle::FluentArgs;
+
+use crate::{
+    plugins::entities::player::{
+        AuxiliaryVelocity, Being, Bonus, Combo, Distance, Memory, MovementType, Primary, Score,
+        ScoreBonus, ScoreBreakdown,
+    },
+    AchievementProgress, FontsAssets, GameAssetsState, Localization, Settings,
+};
+use glib::*;
+
+#[derive(Component)]
+struct Hud;
+
+#[derive(Component)]
+struct ScoreLabel;
+
+#[derive(Component)]
+struct BestLabel;
+
+#[derive(Component)]
+struct ComboLabel;
+
+#[derive(Component)]
+struct DistanceLabel;
+
+#[derive(Component)]
+struct SpeedLabel;
+
+#[derive(Component)]
+struct SpeedFill;
+
+#[derive(Component)]
+struct MemoryLabel;
+
+#[derive(Component)]
+struct MemoryFill;
+
+#[derive(Component)]
+struct PowerUpList;
+
+/// Points floating up from wherever a bonus got awarded.
+#[derive(Component)]
+struct Popup(Timer);
+
+/// Where the score of the run came from, shown until the player respawns.
+#[derive(Component)]
+struct Breakdown;
+
+pub struct GameHudPlugin;
+
+impl Plugin for GameHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(MovementType::Running), Self::setup)
+            .add_systems(OnEnter(Being::Dead), Self::breakdown)
+            .add_systems(OnExit(Being::Dead), Self::clear_breakdown)
+            .add_systems(
+                Update,
+                (
+                    Self::score,
+                    Self::combo,
+                    Self::travel,
+                    Self::spawn_popups,
+                    Self::popups,
+                    Self::memory,
+                    Self::power_ups,
+                    Self::layout,
+                )
+                    .run_if(in_state(GameAssetsState::Loaded)),
+            );
+    }
+}
+
+impl GameHudPlugin {
+    fn text(fonts: &FontsAssets, size: f32, color: Color) -> TextBundle {
+        let style = TextStyle {
+            font: fonts.vcr.clone(),
+            font_size: size,
+            color,
+        };
+
+        TextBundle::from_section("", style)
+    }
+
+    fn track(width: Val, height: Val) -> NodeBundle {
+        NodeBundle {
+            style: Style {
+                width,
+                height,
+                ..Default::default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+            ..Default::default()
+        }
+    }
+
+    fn fill() -> NodeBundle {
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            background_color: Color::WHITE.into(),
+            ..Default::default()
+        }
+    }
+
+    fn tick(at: f32) -> NodeBundle {
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(at / PLAYER_MAX_MEMORY * 100.0),
+                width: Val::Px(3.0),
+                height: Val::Percent(100.0),
+                ..Default::default()
+            },
+            background_color: Color::rgba(1.0, 1.0, 1.0, 0.6).into(),
+            ..Default::default()
+        }
+    }
+
+    /// Spawned once, the first time the player starts running, and kept around for the
+    /// rest of the session.
+    fn setup(
+        mut commands: Commands,
+        hud: Query<(), With<Hud>>,
+        fonts: Res<FontsAssets>,
+        settings: Res<Settings>,
+    ) {
+        if !hud.is_empty() {
+            return;
+        }
+
+        let column = |align_items: AlignItems| NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items,
+                row_gap: Val::Px(6.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut hud = commands.spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Start,
+                padding: UiRect::all(Val::Px(20.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        hud.insert(Name::new("Hud")).insert(Hud);
+
+        if !settings.accessibility.reduced_motion {
+            hud.insert(Animator::new(Tween::new(
+                EaseFunction::QuadraticInOut,
+                Duration::from_secs(1),
+                UiPositionLens {
+                    start: UiRect::top(Val::Percent(-100.0)),
+                    end: UiRect::top(Val::Percent(0.0)),
+                },
+            )));
+        }
+
+        hud.with_children(|parent| {
+            let mut memory = column(AlignItems::Start);
+            memory.style.width = Val::Percent(25.0);
+
+            parent
+                .spawn(memory)
+                .insert(Name::new("Memory"))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Self::text(&fonts, 20.0, Color::BLACK))
+                        .insert(MemoryLabel);
+                    parent
+                        .spawn(Self::track(Val::Percent(100.0), Val::Px(35.0)))
+                        .with_children(|parent| {
+                            parent.spawn(Self::fill()).insert(MemoryFill);
+                            parent.spawn(Self::tick(PLAYER_MEMORY_WARNING));
+                            parent.spawn(Self::tick(PLAYER_MEMORY_CRITICAL));
+                        });
+                    parent
+                        .spawn(Self::text(&fonts, 18.0, Color::BLACK))
+                        .insert(PowerUpList);
+                });
+
+            parent
+                .spawn(column(AlignItems::Center))
+                .insert(Name::new("Score"))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Self::text(&fonts, 100.0, Color::BLACK))
+                        .insert(Label)
+                        .insert(ScoreLabel);
+                    parent
+                        .spawn(Self::text(&fonts, 24.0, Color::BLACK))
+                        .insert(BestLabel);
+                    parent
+                        .spawn(Self::text(&fonts, 28.0, Color::BLACK))
+                        .insert(ComboLabel);
+                });
+
+            parent
+                .spawn(column(AlignItems::End))
+                .insert(Name::new("Travel"))
+                .with_children(|parent| {
+                    parent
+                        .spawn(Self::text(&fonts, 32.0, Color::BLACK))
+                        .insert(DistanceLabel);
+                    parent
+                        .spawn(Self::text(&fonts, 20.0, Color::BLACK))
+                        .insert(SpeedLabel);
+                    parent
+                        .spawn(Self::track(Val::Px(200.0), Val::Px(10.0)))
+                        .with_children(|parent| {
+                            parent.spawn(Self::fill()).insert(SpeedFill);
+                        });
+                });
+        });
+    }
+
+    fn set(text: &mut Text, value: String) {
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+
+    /// The best score shown is the one from before the run, so it can be beaten.
+    fn score(
+        player: Query<&Score, With<Primary>>,
+        mut score_label: Query<&mut Text, (With<ScoreLabel>, Without<BestLabel>)>,
+        mut best_label: Query<&mut Text, (With<BestLabel>, Without<ScoreLabel>)>,
+        progress: Res<AchievementProgress>,
+        localization: Res<Localization>,
+    ) {
+        let (Ok(score), Ok(mut score_label), Ok(mut best_label)) = (
+            player.get_single(),
+            score_label.get_single_mut(),
+            best_label.get_single_mut(),
+        ) else {
+            return;
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("score", score.value.round());
+        Self::set(&mut score_label, localization.format("score", Some(&args)));
+
+        let best = progress.best_score;
+        let mut args = FluentArgs::new();
+        args.set("score", best.round());
+
+        let value = match best {
+            best if best <= 0.0 => String::new(),
+            best if score.value > best => localization.get("hud-new-best"),
+            _ => localization.format("hud-best", Some(&args)),
+        };
+
+        Self::set(&mut best_label, value);
+    }
+
+    fn combo(
+        player: Query<&Combo, With<Primary>>,
+        mut label: Query<&mut Text, With<ComboLabel>>,
+        localization: Res<Localization>,
+    ) {
+        let (Ok(combo), Ok(mut label)) = (player.get_single(), label.get_single_mut()) else {
+            return;
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("multiplier", format!("{:.2}", combo.multiplier));
+
+        let value = match combo.multiplier > 1.0 {
+            true => localization.format("hud-combo", Some(&args)),
+            false => String::new(),
+        };
+
+        Self::set(&mut label, value);
+    }
+
+    fn travel(
+        player: Query<(&Distance, &AuxiliaryVelocity), With<Primary>>,
+        mut distance_label: Query<&mut Text, (With<DistanceLabel>, Without<SpeedLabel>)>,
+        mut speed_label: Query<&mut Text, (With<SpeedLabel>, Without<DistanceLabel>)>,
+        mut speed_fill: Query<&mut Style, With<SpeedFill>>,
+        localization: Res<Localization>,
+    ) {
+        let (Ok((distance, velocity)), Ok(mut distance_label), Ok(mut speed_label)) = (
+            player.get_single(),
+            distance_label.get_single_mut(),
+            speed_label.get_single_mut(),
+        ) else {
+            return;
+        };
+
+        let speed = (velocity.value.x / PLAYER_MAX_VELOCITY_X).clamp(0.0, 1.0);
+
+        let mut args = FluentArgs::new();
+        args.set("distance", (distance.value / HUD_PIXELS_PER_METER).round());
+        args.set("speed", (speed * 100.0).round());
+
+        Self::set(
+            &mut distance_label,
+            localization.format("hud-distance", Some(&args)),
+        );
+        Self::set(
+            &mut speed_label,
+            localization.format("hud-speed", Some(&args)),
+        );
+
+        for mut fill in speed_fill.iter_mut() {
+            fill.width = Val::Percent(speed * 100.0);
+        }
+    }
+
+    /// Colors of the meter above the warning level, under it and under the critical one.
+    fn colors(colorblind: bool) -> [Color; 3] {
+        match colorblind {
+            // yellow and blue stay apart for every kind of color blindness
+            true => [
+                Color::WHITE,
+                Color::rgb(0.94, 0.89, 0.26),
+                Color::rgb(0.0, 0.45, 0.7),
+            ],
+            false => [
+                Color::WHITE,
+                Color::rgb(1.0, 0.6, 0.2),
+                Color::rgb(0.9, 0.2, 0.2),
+            ],
+        }
+    }
+
+    /// Fills the meter and makes it pulse once the memory gets low, faster when critical.
+    fn memory(
+        player: Query<&Memory, With<Primary>>,
+        mut label: Query<&mut Text, With<MemoryLabel>>,
+        mut fill: Query<(&mut Style, &mut BackgroundColor), With<MemoryFill>>,
+        settings: Res<Settings>,
+        localization: Res<Localization>,
+        time: Res<Time>,
+    ) {
+        let (Ok(memory), Ok(mut label), Ok((mut style, mut color))) = (
+            player.get_single(),
+            label.get_single_mut(),
+            fill.get_single_mut(),
+        ) else {
+            return;
+        };
+
+        let fraction = memory.fraction();
+
+        let mut args = FluentArgs::new();
+        args.set("memory", (fraction * 100.0).round());
+        Self::set(&mut label, localization.format("hud-memory", Some(&args)));
+
+        style.width = Val::Percent(fraction * 100.0);
+
+        let [normal, warning, critical] = Self::colors(settings.accessibility.colorblind);
+        let (level, rate) = match memory.value() {
+            value if value < PLAYER_MEMORY_CRITICAL => (critical, HUD_PULSE_RATE * 2.0),
+            value if value < PLAYER_MEMORY_WARNING => (warning, HUD_PULSE_RATE),
+            _ => (normal, 0.0),
+        };
+
+        let pulse = match settings.accessibility.reduced_motion {
+            true => 1.0,
+            false => 0.7 + 0.3 * (time.elapsed_seconds() * rate * TAU).cos(),
+        };
+
+        let level = level.with_a(pulse);
+        if color.0 != level {
+            color.0 = level;
+        }
+    }
+
+    /// Lists whatever currently speeds up or slows down the memory drain.
+    fn power_ups(
+        player: Query<&Memory, With<Primary>>,
+        mut list: Query<&mut Text, With<PowerUpList>>,
+        localization: Res<Localization>,
+    ) {
+        let (Ok(memory), Ok(mut list)) = (player.get_single(), list.get_single_mut()) else {
+            return;
+        };
+
+        let value = memory
+            .modifiers()
+            .iter()
+            .map(|modifier| {
+                let mut args = FluentArgs::new();
+                args.set("name", localization.get(&modifier.key));
+                args.set("seconds", modifier.timer.remaining_secs().ceil());
+                localization.format("hud-power-up", Some(&args))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Self::set(&mut list, value);
+    }
+
+    fn spawn_popups(
+        mut commands: Commands,
+        mut bonuses: EventReader<ScoreBonus>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        for bonus in bonuses.read() {
+            let mut args = FluentArgs::new();
+            args.set("name", localization.get(bonus.bonus.key()));
+            args.set("points", bonus.points.round());
+
+            let style = TextStyle {
+                font: fonts.vcr.clone(),
+                font_size: 24.0,
+                color: Color::WHITE,
+            };
+
+            commands
+                .spawn(Text2dBundle {
+                    text: Text::from_section(localization.format("popup", Some(&args)), style),
+                    transform: Transform::from_translation(
+                        bonus.position.truncate().extend(PARTICLES_Z + 1.0),
+                    ),
+                    ..Default::default()
+                })
+                .insert(Name::new("Popup"))
+                .insert(Popup(Timer::new(SCORE_POPUP_DURATION, TimerMode::Once)));
+        }
+    }
+
+    /// Rises and fades the popups out, they only fade under reduced motion.
+    fn popups(
+        mut commands: Commands,
+        mut popups: Query<(Entity, &mut Popup, &mut Transform, &mut Text)>,
+        settings: Res<Settings>,
+        time: Res<Time>,
+    ) {
+        for (entity, mut popup, mut transform, mut text) in popups.iter_mut() {
+            if popup.0.tick(time.delta()).finished() {
+                commands.entity(entity).despawn_recursive();
+                continue;
+            }
+
+            if !settings.accessibility.reduced_motion {
+                transform.translation.y += SCORE_POPUP_RISE * time.delta_seconds();
+            }
+
+            let alpha = 1.0 - popup.0.fraction();
+            for section in text.sections.iter_mut() {
+                section.style.color.set_a(alpha);
+            }
+        }
+    }
+
+    fn breakdown(
+        mut commands: Commands,
+        breakdown: Res<ScoreBreakdown>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        let line = |key: &str, count: Option<u32>, points: f32| {
+            let mut args = FluentArgs::new();
+            args.set("name", localization.get(key));
+            args.set("count", count.unwrap_or_default());
+            args.set("points", points.round());
+
+            match count {
+                Some(_) => localization.format("breakdown-bonus", Some(&args)),
+                None => localization.format("breakdown-line", Some(&args)),
+            }
+        };
+
+        let mut lines = vec![localization.get("breakdown-title")];
+        lines.push(line("breakdown-distance", None, breakdown.distance));
+        lines.extend(Bonus::ALL.iter().map(|bonus| {
+            let (count, points) = breakdown.bonuses.get(bonus).copied().unwrap_or_default();
+            line(bonus.key(), Some(count), points)
+        }));
+
+        let mut args = FluentArgs::new();
+        args.set("multiplier", format!("{:.2}", breakdown.best_combo));
+        lines.push(localization.format("breakdown-combo", Some(&args)));
+        lines.push(line("breakdown-total", None, breakdown.total()));
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Name::new("Breakdown"))
+            .insert(Breakdown)
+            .with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(8.0),
+                            padding: UiRect::all(Val::Px(24.0)),
+                            ..Default::default()
+                        },
+                        background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        for (i, line) in lines.into_iter().enumerate() {
+                            let size = if i == 0 { 36.0 } else { 22.0 };
+                            let mut text = Self::text(&fonts, size, Color::WHITE);
+                            text.text.sections[0].value = line;
+                            parent.spawn(text);
+                        }
+                    });
+            });
+    }
+
+    fn clear_breakdown(mut commands: Commands, breakdown: Query<Entity, With<Breakdown>>) {
+        for entity in breakdown.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Stacks the hud once the virtual screen gets drawn narrower than
+    /// `HUD_COMPACT_WIDTH`, the text itself already follows `UiScale`.
+    fn layout(ui_scale: Res<UiScale>, mut hud: Query<(&mut Style, Ref<Hud>)>) {
+        let Ok((mut style, hud)) = hud.get_single_mut() else {
+            return;
+        };
+
+        if !ui_scale.is_changed() && !hud.is_added() {
+            return;
+        }
+
+        let compact = APP_WINDOW_DESIRED_WITH * ui_scale.0 < HUD_COMPACT_WIDTH;
+
+        style.flex_direction = match compact {
+            true => FlexDirection::Column,
+            false => FlexDirection::Row,
+        };
+        style.row_gap = Val::Px(12.0);
+    }
+}
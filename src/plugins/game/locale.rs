@@ -0,0 +1,268 @@
+use std::{fmt, sync::Arc};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::{BoxedFuture, HashMap},
+};
+use bevy_asset_loader::prelude::*;
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+use crate::{FontsAssets, GameAssetsState, Settings};
+
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// A parsed Fluent string table, one per language.
+#[derive(Asset, TypePath)]
+pub struct Locale(pub Arc<FluentResource>);
+
+#[derive(Debug)]
+pub enum LocaleError {
+    Io(std::io::Error),
+    Utf8(std::string::FromUtf8Error),
+}
+
+impl fmt::Display for LocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read locale: {err}"),
+            Self::Utf8(err) => write!(f, "locale is not valid utf-8: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LocaleError {}
+
+#[derive(Default)]
+struct LocaleLoader;
+
+impl AssetLoader for LocaleLoader {
+    type Asset = Locale;
+    type Settings = ();
+    type Error = LocaleError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(LocaleError::Io)?;
+            let source = String::from_utf8(bytes).map_err(LocaleError::Utf8)?;
+
+            // entries that fail to parse are dropped, the rest of the table is still usable
+            let resource = FluentResource::try_new(source).unwrap_or_else(|(resource, errors)| {
+                for err in errors {
+                    warn!("{}: {err}", load_context.path().display());
+                }
+                resource
+            });
+
+            Ok(Locale(Arc::new(resource)))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ftl"]
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct LocaleAssets {
+    #[asset(
+        paths(
+            "embedded://locales/en.ftl",
+            "embedded://locales/es.ftl",
+            "embedded://locales/ru.ftl"
+        ),
+        collection(typed, mapped)
+    )]
+    pub locales: HashMap<AssetFileStem, Handle<Locale>>,
+}
+
+impl LocaleAssets {
+    /// Ids of every language shipped with the game, sorted.
+    pub fn languages(&self) -> Vec<String> {
+        let mut languages = self
+            .locales
+            .keys()
+            .map(|stem| stem.as_ref().to_string())
+            .collect::<Vec<_>>();
+        languages.sort();
+        languages
+    }
+
+    fn get(&self, language: &str) -> Option<&Handle<Locale>> {
+        self.locales.get(language)
+    }
+}
+
+/// Text lookups for the language picked in the settings, falling back to english and
+/// then to the key itself for anything missing.
+#[derive(Resource, Default)]
+pub struct Localization {
+    language: String,
+    bundle: Option<FluentBundle<Arc<FluentResource>>>,
+    fallback: Option<FluentBundle<Arc<FluentResource>>>,
+}
+
+impl Localization {
+    fn bundle(language: &str, locale: &Locale) -> FluentBundle<Arc<FluentResource>> {
+        let id = language.parse::<LanguageIdentifier>().unwrap_or_default();
+
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // the isolation marks have no glyph in the game's fonts
+        bundle.set_use_isolating(false);
+        bundle.add_resource_overriding(locale.0.clone());
+        bundle
+    }
+
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn get(&self, key: &str) -> String {
+        self.format(key, None)
+    }
+
+    pub fn format(&self, key: &str, args: Option<&FluentArgs>) -> String {
+        [&self.bundle, &self.fallback]
+            .into_iter()
+            .flatten()
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(key)?.value()?;
+                let mut errors = vec![];
+                Some(
+                    bundle
+                        .format_pattern(pattern, args, &mut errors)
+                        .into_owned(),
+                )
+            })
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Whether the language needs the fallback font, the pixel font only covering
+    /// latin scripts.
+    pub fn needs_fallback_font(&self) -> bool {
+        self.get("language-font") == "fallback"
+    }
+
+    pub fn font(&self, fonts: &FontsAssets) -> Handle<Font> {
+        match self.needs_fallback_font() {
+            true => fonts.fallback.clone(),
+            false => fonts.vcr.clone(),
+        }
+    }
+}
+
+/// Text whose first section is kept in sync with the string behind the key.
+#[derive(Component, Debug, Clone)]
+pub struct Localized(pub String);
+
+impl Localized {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+}
+
+pub struct GameLocalePlugin;
+
+impl Plugin for GameLocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Locale>()
+            .init_asset_loader::<LocaleLoader>()
+            .init_resource::<Localization>()
+            .add_systems(
+                Update,
+                (Self::switch, Self::relabel, Self::refont)
+                    .chain()
+                    .run_if(in_state(GameAssetsState::Loaded)),
+            )
+            .add_systems(
+                PostUpdate,
+                Self::refont_added.run_if(in_state(GameAssetsState::Loaded)),
+            );
+    }
+}
+
+impl GameLocalePlugin {
+    /// Rebuilds the string tables whenever the language setting changes.
+    fn switch(
+        mut localization: ResMut<Localization>,
+        settings: Res<Settings>,
+        assets: Res<LocaleAssets>,
+        locales: Res<Assets<Locale>>,
+    ) {
+        if localization.language == settings.language {
+            return;
+        }
+
+        let load = |language: &str| {
+            assets
+                .get(language)
+                .and_then(|handle| locales.get(handle))
+                .map(|locale| Localization::bundle(language, locale))
+        };
+
+        *localization = Localization {
+            language: settings.language.clone(),
+            bundle: load(&settings.language),
+            fallback: load(DEFAULT_LANGUAGE),
+        };
+    }
+
+    fn relabel(localization: Res<Localization>, mut labels: Query<(Ref<Localized>, &mut Text)>) {
+        for (localized, mut text) in labels.iter_mut() {
+            if !localization.is_changed() && !localized.is_changed() {
+                continue;
+            }
+
+            if let Some(section) = text.sections.first_mut() {
+                section.value = localization.get(&localized.0);
+            }
+        }
+    }
+
+    fn swap_fonts(text: &mut Text, localization: &Localization, fonts: &FontsAssets) {
+        let font = localization.font(fonts);
+
+        for section in text.sections.iter_mut() {
+            let pixel = section.style.font == fonts.vcr || section.style.font == fonts.fallback;
+
+            if pixel && section.style.font != font {
+                section.style.font = font.clone();
+            }
+        }
+    }
+
+    fn refont(
+        localization: Res<Localization>,
+        fonts: Res<FontsAssets>,
+        mut texts: Query<&mut Text>,
+    ) {
+        if !localization.is_changed() {
+            return;
+        }
+
+        for mut text in texts.iter_mut() {
+            Self::swap_fonts(&mut text, &localization, &fonts);
+        }
+    }
+
+    /// Texts spawned after a switch start out with the pixel font.
+    fn refont_added(
+        localization: Res<Localization>,
+        fonts: Res<FontsAssets>,
+        mut texts: Query<&mut Text, Added<Text>>,
+    ) {
+        for mut text in texts.iter_mut() {
+            Self::swap_fonts(&mut text, &localization, &fonts);
+        }
+    }
+}
@@ -1,17 +1,55 @@
 use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
 
-use crate::{GameAssetsState, GameState, TextureAssets};
+use fluent_bundle::FluentArgs;
+
+use glib::ACCESSIBILITY_GAME_SPEEDS;
+
+use super::sounds::{ChannelLevel, Ui};
+use crate::{
+    plugins::entities::player::Skins, AchievementProgress, AudioAssets, DailyChallenge,
+    DailyRecords, DataAssets, FontsAssets, GameAssetsState, GameState, LocaleAssets, Localization,
+    Localized, Settings, TextureAssets, Versus,
+};
 
 #[derive(Component)]
 struct Menu;
 
+/// Skin picker shown under the title until the game starts.
+#[derive(Component)]
+struct Customization;
+
+#[derive(Component)]
+struct SkinLabel;
+
+#[derive(Component)]
+struct PaletteLabel;
+
+#[derive(Component)]
+struct LanguageLabel;
+
+#[derive(Component)]
+struct AccessibilityLabel;
+
+#[derive(Component)]
+struct VersusLabel;
+
 pub struct GameMenuPlugin;
 impl Plugin for GameMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .add_systems(OnEnter(GameAssetsState::Loaded), Self::setup_customization)
+            .add_systems(OnEnter(GameState::Resumed), Self::close_customization)
             .add_systems(
                 Update,
-                Self::wait
+                (
+                    Self::wait,
+                    Self::customize,
+                    Self::configure_accessibility,
+                    Self::configure_versus,
+                    Self::label_customization,
+                )
+                    .chain()
                     .run_if(in_state(GameState::Paused))
                     .run_if(in_state(GameAssetsState::Loaded)),
             )
@@ -36,20 +74,314 @@ impl GameMenuPlugin {
         }
     }
 
+    /// `Space` starts an endless run, `D` the daily challenge and `V` a versus run.
     fn start(
         mut query: Query<&mut Visibility, With<Menu>>,
         mut game_state: ResMut<NextState<GameState>>,
+        mut challenge: ResMut<DailyChallenge>,
+        mut versus: ResMut<Versus>,
+        records: Res<DailyRecords>,
         input: Res<ButtonInput<KeyCode>>,
     ) {
         if query.is_empty() {
             return;
         }
 
+        let daily = input.just_pressed(KeyCode::KeyD);
+        let two_players = input.just_pressed(KeyCode::KeyV);
+
         for mut visibility in query.iter_mut() {
-            if input.just_pressed(KeyCode::Space) {
+            if input.just_pressed(KeyCode::Space) || daily || two_players {
                 *visibility = Visibility::Hidden;
                 game_state.set(GameState::Resumed);
             }
         }
+
+        if daily {
+            challenge.active = true;
+            challenge.scored = !records.played(challenge.day);
+        }
+
+        if two_players {
+            versus.active = true;
+        }
+    }
+
+    fn setup_customization(mut commands: Commands, fonts: Res<FontsAssets>) {
+        let style = |font_size: f32| TextStyle {
+            font: fonts.vcr.clone(),
+            font_size,
+            color: Color::WHITE,
+        };
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    bottom: Val::Px(40.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Name::new("Customization"))
+            .insert(Customization)
+            .with_children(|parent| {
+                parent
+                    .spawn(TextBundle::from_section("", style(32.0)))
+                    .insert(SkinLabel);
+                parent
+                    .spawn(TextBundle::from_section("", style(32.0)))
+                    .insert(PaletteLabel);
+                parent
+                    .spawn(TextBundle::from_section("", style(32.0)))
+                    .insert(LanguageLabel);
+                parent
+                    .spawn(
+                        TextBundle::from_section("", style(18.0))
+                            .with_text_justify(JustifyText::Center),
+                    )
+                    .insert(AccessibilityLabel);
+                parent
+                    .spawn(TextBundle::from_section("", style(18.0)))
+                    .insert(VersusLabel);
+                parent
+                    .spawn(TextBundle::from_section("", style(18.0)))
+                    .insert(Localized::new("menu-hint"));
+            });
+    }
+
+    fn close_customization(mut commands: Commands, query: Query<Entity, With<Customization>>) {
+        for entity in query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    /// Steps `current` by `step` through `ids`, wrapping around at both ends.
+    fn cycle<'a>(ids: &[&'a str], current: &str, step: isize) -> Option<&'a str> {
+        if ids.is_empty() {
+            return None;
+        }
+
+        let index = ids.iter().position(|id| *id == current).unwrap_or(0) as isize;
+        let next = (index + step).rem_euclid(ids.len() as isize) as usize;
+
+        Some(ids[next])
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn customize(
+        mut settings: ResMut<Settings>,
+        progress: Res<AchievementProgress>,
+        data: Res<DataAssets>,
+        skins: Res<Assets<Skins>>,
+        locales: Res<LocaleAssets>,
+        input: Res<ButtonInput<KeyCode>>,
+        assets: Res<AudioAssets>,
+        ui: Res<AudioChannel<Ui>>,
+    ) {
+        let Some(skins) = skins.get(&data.skins) else {
+            return;
+        };
+
+        let step = |previous: KeyCode, next: KeyCode| {
+            (input.just_pressed(next) as isize) - (input.just_pressed(previous) as isize)
+        };
+
+        let sheet_step = step(KeyCode::ArrowLeft, KeyCode::ArrowRight);
+        let palette_step = step(KeyCode::ArrowDown, KeyCode::ArrowUp);
+        let language_step = input.just_pressed(KeyCode::KeyL) as isize;
+
+        if sheet_step == 0 && palette_step == 0 && language_step == 0 {
+            return;
+        }
+
+        let sheets = skins
+            .sheets
+            .iter()
+            .map(|sheet| sheet.id.as_str())
+            .collect::<Vec<_>>();
+
+        let palettes = skins
+            .palettes
+            .iter()
+            .filter(|palette| Skins::is_available(palette, &progress))
+            .map(|palette| palette.id.as_str())
+            .collect::<Vec<_>>();
+
+        if let Some(sheet) = Self::cycle(&sheets, &settings.skin.sheet, sheet_step) {
+            settings.skin.sheet = sheet.to_string();
+        }
+
+        if let Some(palette) = Self::cycle(&palettes, &settings.skin.palette, palette_step) {
+            settings.skin.palette = palette.to_string();
+        }
+
+        let languages = locales.languages();
+        let languages = languages.iter().map(String::as_str).collect::<Vec<_>>();
+
+        if let Some(language) = Self::cycle(&languages, &settings.language, language_step) {
+            settings.language = language.to_string();
+        }
+
+        ui.play(assets.select.clone())
+            .with_volume(0.3 * Ui::level(&settings.audio));
+    }
+
+    /// Number keys toggle the accessibility options, the game speed steps through
+    /// `ACCESSIBILITY_GAME_SPEEDS`.
+    fn configure_accessibility(
+        mut settings: ResMut<Settings>,
+        input: Res<ButtonInput<KeyCode>>,
+        assets: Res<AudioAssets>,
+        ui: Res<AudioChannel<Ui>>,
+    ) {
+        let mut accessibility = settings.accessibility.clone();
+
+        for (key, option) in [
+            (KeyCode::Digit1, &mut accessibility.reduced_motion),
+            (KeyCode::Digit2, &mut accessibility.high_contrast),
+            (KeyCode::Digit3, &mut accessibility.colorblind),
+            (KeyCode::Digit5, &mut accessibility.auto_jump),
+        ] {
+            if input.just_pressed(key) {
+                *option = !*option;
+            }
+        }
+
+        if input.just_pressed(KeyCode::Digit4) {
+            let index = ACCESSIBILITY_GAME_SPEEDS
+                .iter()
+                .position(|speed| *speed == accessibility.game_speed)
+                .map_or(0, |index| index + 1);
+
+            accessibility.game_speed =
+                ACCESSIBILITY_GAME_SPEEDS[index % ACCESSIBILITY_GAME_SPEEDS.len()];
+        }
+
+        if settings.accessibility != accessibility {
+            settings.accessibility = accessibility;
+            ui.play(assets.select.clone())
+                .with_volume(0.3 * Ui::level(&settings.audio));
+        }
+    }
+
+    /// `C` picks between a shared camera and split screen for versus runs.
+    fn configure_versus(
+        mut settings: ResMut<Settings>,
+        input: Res<ButtonInput<KeyCode>>,
+        assets: Res<AudioAssets>,
+        ui: Res<AudioChannel<Ui>>,
+    ) {
+        if input.just_pressed(KeyCode::KeyC) {
+            settings.split_screen = !settings.split_screen;
+            ui.play(assets.select.clone())
+                .with_volume(0.3 * Ui::level(&settings.audio));
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn label_customization(
+        settings: Res<Settings>,
+        progress: Res<AchievementProgress>,
+        data: Res<DataAssets>,
+        skins: Res<Assets<Skins>>,
+        localization: Res<Localization>,
+        mut labels: ParamSet<(
+            Query<&mut Text, With<SkinLabel>>,
+            Query<&mut Text, With<PaletteLabel>>,
+            Query<&mut Text, With<LanguageLabel>>,
+            Query<&mut Text, With<AccessibilityLabel>>,
+            Query<&mut Text, With<VersusLabel>>,
+        )>,
+    ) {
+        let Some(skins) = skins.get(&data.skins) else {
+            return;
+        };
+
+        let sheet = skins
+            .sheet(&settings.skin.sheet)
+            .or(skins.sheets.first())
+            .map_or(String::new(), |sheet| localization.get(&sheet.name()));
+
+        let palette = skins
+            .palette(&settings.skin.palette)
+            .filter(|palette| Skins::is_available(palette, &progress))
+            .or(skins.palettes.first())
+            .map_or(String::new(), |palette| localization.get(&palette.name()));
+
+        let locked = skins
+            .palettes
+            .iter()
+            .filter(|palette| !Skins::is_available(palette, &progress))
+            .count();
+
+        let mut args = FluentArgs::new();
+        args.set("name", sheet);
+        let sheet = localization.format("menu-skin", Some(&args));
+
+        let mut args = FluentArgs::new();
+        args.set("name", palette);
+        args.set("locked", locked);
+        let palette = match locked {
+            0 => localization.format("menu-palette", Some(&args)),
+            _ => localization.format("menu-palette-locked", Some(&args)),
+        };
+
+        let mut args = FluentArgs::new();
+        args.set("name", localization.get("language-name"));
+        let language = localization.format("menu-language", Some(&args));
+
+        let toggle = |enabled: bool| match enabled {
+            true => localization.get("option-on"),
+            false => localization.get("option-off"),
+        };
+
+        let accessibility = &settings.accessibility;
+
+        let mut args = FluentArgs::new();
+        args.set("motion", toggle(accessibility.reduced_motion));
+        args.set("contrast", toggle(accessibility.high_contrast));
+        args.set("colorblind", toggle(accessibility.colorblind));
+        args.set("speed", (accessibility.game_speed * 100.0).round());
+        args.set("jump", toggle(accessibility.auto_jump));
+        let accessibility = format!(
+            "{}\n{}",
+            localization.format("menu-accessibility-visuals", Some(&args)),
+            localization.format("menu-accessibility-assists", Some(&args)),
+        );
+
+        let mut args = FluentArgs::new();
+        args.set("split", toggle(settings.split_screen));
+        let versus = localization.format("menu-versus", Some(&args));
+
+        let set = |mut label: Mut<Text>, value: String| {
+            if label.sections[0].value != value {
+                label.sections[0].value = value;
+            }
+        };
+
+        if let Ok(label) = labels.p0().get_single_mut() {
+            set(label, sheet);
+        }
+
+        if let Ok(label) = labels.p1().get_single_mut() {
+            set(label, palette);
+        }
+
+        if let Ok(label) = labels.p2().get_single_mut() {
+            set(label, language);
+        }
+
+        if let Ok(label) = labels.p3().get_single_mut() {
+            set(label, accessibility);
+        }
+
+        if let Ok(label) = labels.p4().get_single_mut() {
+            set(label, versus);
+        }
     }
 }
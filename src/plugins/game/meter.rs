@@ -0,0 +1,283 @@
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::prelude::*;
+
+/// Temporarily scales how fast a meter drains or refills, e.g. after a pickup.
+#[derive(Reflect, Debug, Clone)]
+pub struct MeterModifier {
+    /// key of the name in the string tables
+    pub key: String,
+    pub drain: f32,
+    pub regen: f32,
+    pub timer: Timer,
+}
+
+impl MeterModifier {
+    pub fn new(key: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            key: key.into(),
+            drain: 1.0,
+            regen: 1.0,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    /// Multiplies the drain by `factor` while the modifier lasts.
+    pub fn drain(mut self, factor: f32) -> Self {
+        self.drain = factor;
+        self
+    }
+}
+
+/// A value kept between zero and `max` that drains and refills over time, `M` tells
+/// the meters of an entity apart.
+#[derive(Component, Reflect, Debug, Clone)]
+pub struct Meter<M: TypePath + Send + Sync + 'static> {
+    value: f32,
+    pub max: f32,
+    /// units lost every second, before modifiers
+    pub drain: f32,
+    /// units gained every second, before modifiers
+    pub regen: f32,
+    /// multiplies the drain, set by whatever the meter depends on
+    pub scale: f32,
+    modifiers: Vec<MeterModifier>,
+    /// levels sending a `MeterThreshold` when crossed
+    thresholds: Vec<f32>,
+    /// value the thresholds were last checked against
+    checked: f32,
+    #[reflect(ignore)]
+    marker: PhantomData<M>,
+}
+
+impl<M: TypePath + Send + Sync + 'static> Default for Meter<M> {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl<M: TypePath + Send + Sync + 'static> Meter<M> {
+    /// A full meter that neither drains nor refills.
+    pub fn new(max: f32) -> Self {
+        Self {
+            value: max,
+            max,
+            drain: 0.0,
+            regen: 0.0,
+            scale: 1.0,
+            modifiers: Vec::new(),
+            thresholds: Vec::new(),
+            checked: max,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn with_drain(mut self, drain: f32) -> Self {
+        self.drain = drain;
+        self
+    }
+
+    pub fn with_thresholds(mut self, thresholds: &[f32]) -> Self {
+        self.thresholds = thresholds.to_vec();
+        self
+    }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn fraction(&self) -> f32 {
+        match self.max > 0.0 {
+            true => self.value / self.max,
+            false => 0.0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value <= 0.0
+    }
+
+    pub fn set(&mut self, value: f32) {
+        self.value = value.clamp(0.0, self.max.max(0.0));
+    }
+
+    pub fn add(&mut self, amount: f32) {
+        self.set(self.value + amount);
+    }
+
+    pub fn modifiers(&self) -> &[MeterModifier] {
+        &self.modifiers
+    }
+
+    /// Applies the modifier, restarting it if one with the same key is already running.
+    pub fn modify(&mut self, modifier: MeterModifier) {
+        match self.modifiers.iter_mut().find(|m| m.key == modifier.key) {
+            Some(running) => *running = modifier,
+            None => self.modifiers.push(modifier),
+        }
+    }
+
+    /// Units gained every second with the current modifiers, negative when draining.
+    pub fn rate(&self) -> f32 {
+        let (drain, regen) = self
+            .modifiers
+            .iter()
+            .fold((1.0, 1.0), |(drain, regen), modifier| {
+                (drain * modifier.drain, regen * modifier.regen)
+            });
+
+        self.regen * regen - self.drain * self.scale * drain
+    }
+
+    pub fn tick(&mut self, delta: Duration) {
+        self.add(self.rate() * delta.as_secs_f32());
+
+        self.modifiers
+            .retain_mut(|modifier| !modifier.timer.tick(delta).finished());
+    }
+
+    /// Thresholds crossed since the last call, along with whether the value fell below
+    /// them.
+    fn crossed(&mut self) -> Vec<(f32, bool)> {
+        let (before, after) = (self.checked, self.value);
+        self.checked = after;
+
+        self.thresholds
+            .iter()
+            .filter_map(|&level| match (before >= level, after >= level) {
+                (true, false) => Some((level, true)),
+                (false, true) => Some((level, false)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Sent when a `Meter<M>` goes past one of its thresholds.
+#[derive(Event, Debug)]
+pub struct MeterThreshold<M: TypePath + Send + Sync + 'static> {
+    pub level: f32,
+    pub falling: bool,
+    marker: PhantomData<M>,
+}
+
+impl<M: TypePath + Send + Sync + 'static> MeterThreshold<M> {
+    pub fn fell_below(&self, level: f32) -> bool {
+        self.falling && self.level == level
+    }
+}
+
+/// Sends the threshold events of every `Meter<M>`, ticking them is left to whoever owns
+/// the meter through `GameMeterPlugin::<M>::tick` so it can decide when it runs.
+#[derive(Default)]
+pub struct GameMeterPlugin<M> {
+    marker: PhantomData<M>,
+}
+
+impl<M> Plugin for GameMeterPlugin<M>
+where
+    M: TypePath + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.add_event::<MeterThreshold<M>>()
+            .add_systems(PostUpdate, Self::thresholds);
+    }
+}
+
+impl<M> GameMeterPlugin<M>
+where
+    M: TypePath + Send + Sync + 'static,
+{
+    pub fn tick(mut meters: Query<&mut Meter<M>>, time: Res<Time>) {
+        for mut meter in meters.iter_mut() {
+            meter.tick(time.delta());
+        }
+    }
+
+    fn thresholds(mut meters: Query<&mut Meter<M>>, mut events: EventWriter<MeterThreshold<M>>) {
+        for mut meter in meters.iter_mut() {
+            if meter.checked == meter.value {
+                continue;
+            }
+
+            for (level, falling) in meter.crossed() {
+                events.send(MeterThreshold {
+                    level,
+                    falling,
+                    marker: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_meter {
+    use std::time::Duration;
+
+    use bevy::reflect::TypePath;
+
+    use super::{Meter, MeterModifier};
+
+    #[derive(TypePath)]
+    struct Test;
+
+    fn meter() -> Meter<Test> {
+        Meter::new(100.0)
+    }
+
+    #[test]
+    fn set_clamps_to_max() {
+        let mut meter = meter();
+        meter.set(250.0);
+        assert_eq!(meter.value(), 100.0);
+    }
+
+    #[test]
+    fn add_never_goes_below_zero() {
+        let mut meter = meter();
+        meter.add(-1000.0);
+        assert_eq!(meter.value(), 0.0);
+        assert!(meter.is_empty());
+    }
+
+    #[test]
+    fn drain_stops_at_zero() {
+        let mut meter = meter().with_drain(60.0);
+        meter.tick(Duration::from_secs(5));
+        assert_eq!(meter.value(), 0.0);
+    }
+
+    #[test]
+    fn regen_stops_at_max() {
+        let mut meter = meter();
+        meter.regen = 10.0;
+        meter.set(95.0);
+        meter.tick(Duration::from_secs(2));
+        assert_eq!(meter.value(), 100.0);
+    }
+
+    #[test]
+    fn modifiers_scale_the_drain_until_they_run_out() {
+        let mut meter = meter().with_drain(10.0);
+        meter.scale = 2.0;
+        meter.modify(MeterModifier::new("half", Duration::from_secs(1)).drain(0.5));
+        assert_eq!(meter.rate(), -10.0);
+
+        meter.tick(Duration::from_secs(1));
+        assert_eq!(meter.value(), 90.0);
+        assert!(meter.modifiers().is_empty());
+        assert_eq!(meter.rate(), -20.0);
+    }
+
+    #[test]
+    fn thresholds_fire_once_per_crossing() {
+        let mut meter = meter().with_thresholds(&[25.0]);
+        meter.set(20.0);
+        assert_eq!(meter.crossed(), vec![(25.0, true)]);
+        assert!(meter.crossed().is_empty());
+
+        meter.set(30.0);
+        assert_eq!(meter.crossed(), vec![(25.0, false)]);
+    }
+}
@@ -0,0 +1,366 @@
+use bevy::prelude::*;
+use fluent_bundle::FluentArgs;
+use serde::Deserialize;
+
+use crate::{
+    plugins::entities::{
+        player::{Being, Distance, Player},
+        terrain::{Platform, Scenery},
+    },
+    AchievementProgress, DataAssets, FontsAssets, GameState, GameplaySet, Localization,
+    TextureAssets,
+};
+use glib::*;
+
+#[derive(Resource, Deserialize, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Biome {
+    #[default]
+    Downtown,
+    Industrial,
+    Outskirts,
+}
+
+impl Biome {
+    /// Key of the name in the string tables.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Downtown => "biome-downtown",
+            Self::Industrial => "biome-industrial",
+            Self::Outskirts => "biome-outskirts",
+        }
+    }
+
+    pub fn sky(&self) -> Color {
+        match self {
+            Self::Downtown => WORLD_BACKGROUND_COLOR,
+            Self::Industrial => Color::rgb(0.8, 0.72, 0.58),
+            Self::Outskirts => Color::rgb(0.27, 0.29, 0.45),
+        }
+    }
+
+    /// Color the buildings spawned in the biome get multiplied by.
+    pub fn tint(&self) -> Color {
+        match self {
+            Self::Downtown => Color::WHITE,
+            Self::Industrial => Color::rgb(0.92, 0.84, 0.72),
+            Self::Outskirts => Color::rgb(0.62, 0.64, 0.82),
+        }
+    }
+}
+
+/// Scales the gaps between platforms, raised by milestones as the run goes on.
+#[derive(Resource, Reflect, Debug, Clone, Copy)]
+pub struct Difficulty(pub f32);
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Changes applied once the player gets past `distance` meters.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct MilestoneChange {
+    pub distance: f32,
+    #[serde(default)]
+    pub biome: Option<Biome>,
+    #[serde(default)]
+    pub difficulty: Option<f32>,
+}
+
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct Milestones {
+    /// meters between two markers
+    pub interval: f32,
+    pub changes: Vec<MilestoneChange>,
+}
+
+impl Milestones {
+    /// Changes past `from` meters up to `to` included, in the order they come up.
+    fn between(&self, from: f32, to: f32) -> impl Iterator<Item = &MilestoneChange> {
+        let mut changes = self
+            .changes
+            .iter()
+            .filter(|change| from < change.distance && change.distance <= to)
+            .collect::<Vec<_>>();
+
+        changes.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        changes.into_iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarkerKind {
+    Meters(f32),
+    /// where the longest run so far ended
+    Best,
+}
+
+/// A board standing in the world, `distance` being the player's `Distance` once
+/// it's reached.
+#[derive(Component, Debug)]
+pub struct MilestoneMarker {
+    pub distance: f32,
+    pub kind: MarkerKind,
+    passed: bool,
+}
+
+/// Sent when the player goes past a marker.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MilestonePassed {
+    pub kind: MarkerKind,
+}
+
+/// The markers placed so far during the current run.
+#[derive(Resource, Default, Debug)]
+struct MilestoneTrack {
+    /// meters of the next marker to place
+    next: f32,
+    best: bool,
+    /// meters the changes got applied up to
+    reached: f32,
+}
+
+pub struct GameMilestonesPlugin;
+
+impl Plugin for GameMilestonesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Difficulty>()
+            .init_resource::<Biome>()
+            .init_resource::<MilestoneTrack>()
+            .add_event::<MilestonePassed>()
+            .add_systems(OnEnter(Being::Alive), Self::reset)
+            .add_systems(
+                FixedUpdate,
+                (Self::place, Self::pass)
+                    .chain()
+                    .in_set(GameplaySet)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(Being::Alive)),
+            )
+            .add_systems(Update, (Self::sky, Self::tint))
+            .register_type::<Biome>()
+            .register_type::<Difficulty>();
+    }
+}
+
+impl GameMilestonesPlugin {
+    /// Every run starts back downtown, without the markers of the previous one.
+    fn reset(
+        mut commands: Commands,
+        markers: Query<Entity, With<MilestoneMarker>>,
+        mut track: ResMut<MilestoneTrack>,
+        mut biome: ResMut<Biome>,
+        mut difficulty: ResMut<Difficulty>,
+    ) {
+        for entity in markers.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        *track = MilestoneTrack::default();
+        *biome = Biome::default();
+        *difficulty = Difficulty::default();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn place(
+        mut commands: Commands,
+        player: Query<(&Transform, &Distance), With<Player>>,
+        mut track: ResMut<MilestoneTrack>,
+        progress: Res<AchievementProgress>,
+        data: Res<DataAssets>,
+        milestones: Res<Assets<Milestones>>,
+        textures: Res<TextureAssets>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        // markers are laid out ahead of the leading runner
+        let leader = player
+            .iter()
+            .max_by(|(_, a), (_, b)| a.value.total_cmp(&b.value));
+
+        let (Some((transform, distance)), Some(milestones)) =
+            (leader, milestones.get(&data.milestones))
+        else {
+            return;
+        };
+
+        if milestones.interval <= 0.0 {
+            return;
+        }
+
+        let mut spawn = |at: f32, kind: MarkerKind, label: String| {
+            let x = transform.translation.x + at - distance.value;
+
+            commands
+                .spawn(SpriteBundle {
+                    texture: textures.street_board.clone(),
+                    transform: Transform {
+                        translation: Vec3::new(x, MILESTONE_MARKER_Y, MILESTONE_MARKER_Z),
+                        scale: WORLD_SPRITE_SCALE,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .insert(Name::new("Milestone Marker"))
+                .insert(MilestoneMarker {
+                    distance: at,
+                    kind,
+                    passed: false,
+                })
+                .insert(Scenery)
+                .with_children(|parent| {
+                    let style = TextStyle {
+                        font: fonts.vcr.clone(),
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                    };
+
+                    parent.spawn(Text2dBundle {
+                        text: Text::from_section(label, style).with_justify(JustifyText::Center),
+                        transform: Transform::from_xyz(0.0, 8.0, 0.1),
+                        ..Default::default()
+                    });
+                });
+        };
+
+        let ahead = distance.value + MILESTONE_SPAWN_AHEAD;
+        let best = progress.best_distance;
+
+        if !track.best && best > 0.0 && best <= ahead {
+            track.best = true;
+
+            if best > distance.value {
+                spawn(best, MarkerKind::Best, localization.get("milestone-best"));
+            }
+        }
+
+        loop {
+            let meters = track.next + milestones.interval;
+            let at = meters * HUD_PIXELS_PER_METER;
+
+            if at > ahead {
+                break;
+            }
+
+            let previous = std::mem::replace(&mut track.next, meters);
+
+            let mut args = FluentArgs::new();
+            args.set("distance", meters);
+            let mut label = localization.format("hud-distance", Some(&args));
+
+            // the first marker past a change tells about it
+            if let Some(biome) = milestones
+                .between(previous, meters)
+                .filter_map(|change| change.biome)
+                .last()
+            {
+                label = format!("{label}\n{}", localization.get(biome.key()));
+            }
+
+            spawn(at, MarkerKind::Meters(meters), label);
+        }
+    }
+
+    /// Flags the markers the player just went past and applies the changes of the
+    /// distances crossed since the last tick, markers or not.
+    #[allow(clippy::too_many_arguments)]
+    fn pass(
+        player: Query<&Distance, With<Player>>,
+        mut markers: Query<&mut MilestoneMarker>,
+        mut passed: EventWriter<MilestonePassed>,
+        mut track: ResMut<MilestoneTrack>,
+        mut biome: ResMut<Biome>,
+        mut difficulty: ResMut<Difficulty>,
+        data: Res<DataAssets>,
+        milestones: Res<Assets<Milestones>>,
+    ) {
+        let Some(distance) = player
+            .iter()
+            .map(|distance| distance.value)
+            .reduce(f32::max)
+        else {
+            return;
+        };
+
+        for mut marker in markers.iter_mut() {
+            if marker.passed || marker.distance > distance {
+                continue;
+            }
+
+            marker.passed = true;
+            passed.send(MilestonePassed { kind: marker.kind });
+        }
+
+        let Some(milestones) = milestones.get(&data.milestones) else {
+            return;
+        };
+
+        let meters = distance / HUD_PIXELS_PER_METER;
+
+        for change in milestones.between(track.reached, meters) {
+            if let Some(next) = change.biome {
+                *biome = next;
+            }
+
+            if let Some(next) = change.difficulty {
+                *difficulty = Difficulty(next);
+            }
+        }
+
+        track.reached = track.reached.max(meters);
+    }
+
+    fn sky(biome: Res<Biome>, mut clear_color: ResMut<ClearColor>) {
+        if biome.is_changed() {
+            clear_color.0 = biome.sky();
+        }
+    }
+
+    /// Buildings keep the colors of the biome they were spawned in.
+    fn tint(
+        biome: Res<Biome>,
+        mut sprites: Query<(&mut Sprite, &Parent), Added<Sprite>>,
+        platforms: Query<(), With<Platform>>,
+    ) {
+        for (mut sprite, parent) in sprites.iter_mut() {
+            if platforms.contains(parent.get()) {
+                sprite.color = biome.tint();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_milestones {
+    use super::{Biome, MilestoneChange, Milestones};
+
+    fn change(distance: f32) -> MilestoneChange {
+        MilestoneChange {
+            distance,
+            biome: Some(Biome::Industrial),
+            difficulty: None,
+        }
+    }
+
+    #[test]
+    fn changes_apply_off_the_marker_interval() {
+        let milestones = Milestones {
+            interval: 250.0,
+            changes: vec![change(1600.0), change(1100.0)],
+        };
+
+        let crossed = |from, to| {
+            milestones
+                .between(from, to)
+                .map(|change| change.distance)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(crossed(0.0, 1000.0), Vec::<f32>::new());
+        assert_eq!(crossed(1000.0, 1100.0), vec![1100.0]);
+        assert_eq!(crossed(1100.0, 1100.5), Vec::<f32>::new());
+        assert_eq!(crossed(0.0, 2000.0), vec![1100.0, 1600.0]);
+    }
+}
@@ -1,28 +1,56 @@
+pub mod accessibility;
+pub mod achievements;
 pub mod animation;
 pub mod assets;
+pub mod bot;
+pub mod daily;
+pub mod data;
 pub mod dialog;
 pub mod ground;
+pub mod hud;
+pub mod locale;
 pub mod menu;
+pub mod meter;
+pub mod milestones;
+pub mod narrative;
+pub mod resolution;
 pub mod restart;
 pub mod rpc;
+pub mod settings;
 pub mod setup;
+pub mod sfx;
 pub mod sounds;
 pub mod states;
+pub mod telemetry;
+pub mod timestep;
+pub mod tutorial;
+pub mod versus;
 
 pub mod prelude {
-    use bevy::prelude::*;
-
-    #[derive(Component, Default)]
-    pub struct Responsive;
-
+    pub use super::accessibility::*;
+    pub use super::achievements::*;
     pub use super::animation::*;
     pub use super::assets::*;
+    pub use super::bot::*;
+    pub use super::daily::*;
     pub use super::dialog::*;
     pub use super::ground::*;
+    pub use super::hud::*;
+    pub use super::locale::*;
     pub use super::menu::*;
+    pub use super::meter::*;
+    pub use super::milestones::*;
+    pub use super::narrative::*;
+    pub use super::resolution::*;
     pub use super::restart::*;
     pub use super::rpc::*;
+    pub use super::settings::*;
     pub use super::setup::*;
+    pub use super::sfx::*;
     pub use super::sounds::*;
     pub use super::states::*;
+    pub use super::telemetry::*;
+    pub use super::timestep::*;
+    pub use super::tutorial::*;
+    pub use super::versus::*;
 }
@@ -0,0 +1,289 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::dialog::{Dialog, Line};
+use crate::{
+    plugins::entities::player::{
+        Being, Collected, Died, Distance, Memory, MemoryKind, Milestone, MovementType, Primary,
+        Score,
+    },
+    DataAssets, GameAssetsState, GameState, Localization, MeterThreshold,
+};
+use glib::*;
+
+/// Game moments a story beat can be keyed to.
+#[derive(Deserialize, Event, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NarrativeEvent {
+    Start,
+    Running,
+    Death,
+    Respawn,
+    Milestone,
+    Collected,
+    MemoryWarning,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    Event(NarrativeEvent),
+    /// world units covered since the current run started
+    Distance(f32),
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    ScoreAbove(f32),
+    ScoreBelow(f32),
+    MemoryAbove(f32),
+    MemoryBelow(f32),
+    /// deaths during this session
+    DeathsAtLeast(u32),
+    DeathsBelow(u32),
+}
+
+impl Condition {
+    fn holds(self, narrative: &Narrative) -> bool {
+        match self {
+            Self::ScoreAbove(score) => narrative.score > score,
+            Self::ScoreBelow(score) => narrative.score < score,
+            Self::MemoryAbove(memory) => narrative.memory > memory,
+            Self::MemoryBelow(memory) => narrative.memory < memory,
+            Self::DeathsAtLeast(deaths) => narrative.deaths >= deaths,
+            Self::DeathsBelow(deaths) => narrative.deaths < deaths,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Speaker {
+    /// key of the name in the string tables
+    pub name: String,
+    /// hex color the name is drawn with
+    pub color: String,
+    /// playback rate of the speaker's blips
+    #[serde(default = "Speaker::default_pitch")]
+    pub pitch: f64,
+}
+
+impl Speaker {
+    fn default_pitch() -> f64 {
+        1.0
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ScriptLine {
+    #[serde(default)]
+    pub speaker: Option<String>,
+    /// key of the line in the string tables
+    pub text: String,
+    /// seconds the line stays on screen
+    pub duration: f32,
+    /// the line is left out unless all of these hold
+    #[serde(default)]
+    pub when: Vec<Condition>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Beat {
+    pub id: String,
+    pub trigger: Trigger,
+    #[serde(default)]
+    pub when: Vec<Condition>,
+    /// play at most once per session, otherwise once per run
+    #[serde(default)]
+    pub once: bool,
+    pub lines: Vec<ScriptLine>,
+}
+
+/// Everything said in the game, beats sharing a trigger are tried in order and the
+/// first one whose conditions hold gets played.
+#[derive(Asset, TypePath, Deserialize, Debug)]
+pub struct DialogScript {
+    pub speakers: HashMap<String, Speaker>,
+    pub beats: Vec<Beat>,
+}
+
+impl DialogScript {
+    fn lines(&self, beat: &Beat, narrative: &Narrative, localization: &Localization) -> Vec<Line> {
+        beat.lines
+            .iter()
+            .filter(|line| line.when.iter().all(|condition| condition.holds(narrative)))
+            .map(|line| {
+                let content = Line::new(
+                    localization.get(&line.text),
+                    Duration::from_secs_f32(line.duration),
+                );
+
+                match line.speaker.as_ref().and_then(|id| self.speakers.get(id)) {
+                    Some(speaker) => content.said_by(
+                        localization.get(&speaker.name),
+                        Color::hex(&speaker.color).unwrap_or(Color::WHITE),
+                        speaker.pitch,
+                    ),
+                    None => content,
+                }
+            })
+            .collect()
+    }
+}
+
+/// What the script gets to branch on.
+#[derive(Resource, Default, Debug)]
+pub struct Narrative {
+    pub score: f32,
+    pub memory: f32,
+    pub deaths: u32,
+    pub distance: f32,
+    session: HashSet<String>,
+    run: HashSet<String>,
+}
+
+pub struct GameNarrativePlugin;
+
+impl Plugin for GameNarrativePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Narrative>()
+            .add_event::<NarrativeEvent>()
+            .add_systems(
+                OnEnter(GameState::Resumed),
+                Self::send(NarrativeEvent::Start),
+            )
+            .add_systems(
+                OnEnter(MovementType::Running),
+                Self::send(NarrativeEvent::Running),
+            )
+            .add_systems(
+                OnTransition {
+                    from: Being::Dead,
+                    to: Being::Alive,
+                },
+                (Self::new_run, Self::send(NarrativeEvent::Respawn)).chain(),
+            )
+            .add_systems(
+                Update,
+                (Self::observe, Self::forward, Self::trigger)
+                    .chain()
+                    .run_if(in_state(GameAssetsState::Loaded))
+                    .run_if(in_state(GameState::Resumed)),
+            );
+    }
+}
+
+impl GameNarrativePlugin {
+    fn send(event: NarrativeEvent) -> impl FnMut(EventWriter<NarrativeEvent>) {
+        move |mut events| {
+            events.send(event);
+        }
+    }
+
+    fn new_run(mut narrative: ResMut<Narrative>) {
+        narrative.distance = 0.0;
+        narrative.run.clear();
+    }
+
+    /// Keeps track of the values conditions look at, the last known ones are kept while
+    /// the player is dead.
+    fn observe(
+        player: Query<(&Score, &Memory, &Distance), With<Primary>>,
+        mut narrative: ResMut<Narrative>,
+    ) {
+        let Ok((score, memory, distance)) = player.get_single() else {
+            return;
+        };
+
+        narrative.score = score.value;
+        narrative.memory = memory.value();
+        narrative.distance = distance.value;
+    }
+
+    fn forward(
+        mut events: EventWriter<NarrativeEvent>,
+        mut died: EventReader<Died>,
+        mut milestones: EventReader<Milestone>,
+        mut collected: EventReader<Collected>,
+        mut thresholds: EventReader<MeterThreshold<MemoryKind>>,
+        mut narrative: ResMut<Narrative>,
+    ) {
+        for _ in died.read() {
+            narrative.deaths += 1;
+            events.send(NarrativeEvent::Death);
+        }
+
+        if milestones.read().count() > 0 {
+            events.send(NarrativeEvent::Milestone);
+        }
+
+        if collected.read().count() > 0 {
+            events.send(NarrativeEvent::Collected);
+        }
+
+        if thresholds
+            .read()
+            .any(|threshold| threshold.fell_below(PLAYER_MEMORY_WARNING))
+        {
+            events.send(NarrativeEvent::MemoryWarning);
+        }
+    }
+
+    fn trigger(
+        mut events: EventReader<NarrativeEvent>,
+        mut dialog: Query<&mut Dialog>,
+        mut narrative: ResMut<Narrative>,
+        data: Res<DataAssets>,
+        scripts: Res<Assets<DialogScript>>,
+        localization: Res<Localization>,
+    ) {
+        let events = events.read().copied().collect::<Vec<_>>();
+
+        let Some(script) = scripts.get(&data.dialog) else {
+            return;
+        };
+
+        let Ok(mut dialog) = dialog.get_single_mut() else {
+            return;
+        };
+
+        let beat = script.beats.iter().find(|beat| {
+            let triggered = match beat.trigger {
+                Trigger::Event(event) => events.contains(&event),
+                // distance beats wait for the current dialog to be over
+                Trigger::Distance(distance) => {
+                    narrative.distance >= distance && !dialog.is_playing()
+                }
+            };
+
+            let played = match beat.once {
+                true => narrative.session.contains(&beat.id),
+                false => narrative.run.contains(&beat.id),
+            };
+
+            triggered
+                && !played
+                && beat
+                    .when
+                    .iter()
+                    .all(|condition| condition.holds(&narrative))
+        });
+
+        let Some(beat) = beat else {
+            return;
+        };
+
+        narrative.session.insert(beat.id.clone());
+        narrative.run.insert(beat.id.clone());
+
+        let lines = script.lines(beat, &narrative, &localization);
+        if !lines.is_empty() {
+            dialog.play(lines);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowMode, WindowResized},
+};
+use glib::utils;
+
+/// Keeps the ui laid out for the virtual resolution, the world itself is handled by the
+/// camera projection which always shows at least `APP_WINDOW_DESIRED_WITH` x
+/// `APP_WINDOW_DESIRED_HEIGHT` world units whatever the window size is.
+pub struct GameResolutionPlugin;
+
+impl Plugin for GameResolutionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostStartup, Self::setup)
+            .add_systems(Update, (Self::resize, Self::toggle_fullscreen));
+    }
+}
+
+impl GameResolutionPlugin {
+    fn setup(window: Query<&Window, With<PrimaryWindow>>, mut ui_scale: ResMut<UiScale>) {
+        if let Ok(window) = window.get_single() {
+            ui_scale.0 = utils::virtual_scale(window.width(), window.height());
+        }
+    }
+
+    fn resize(
+        mut events: EventReader<WindowResized>,
+        window: Query<Entity, With<PrimaryWindow>>,
+        mut ui_scale: ResMut<UiScale>,
+    ) {
+        let Ok(primary) = window.get_single() else {
+            return;
+        };
+
+        for event in events.read().filter(|event| event.window == primary) {
+            ui_scale.0 = utils::virtual_scale(event.width, event.height);
+        }
+    }
+
+    fn toggle_fullscreen(
+        mut window: Query<&mut Window, With<PrimaryWindow>>,
+        input: Res<ButtonInput<KeyCode>>,
+    ) {
+        if !input.just_pressed(KeyCode::F11) {
+            return;
+        }
+
+        if let Ok(mut window) = window.get_single_mut() {
+            window.mode = match window.mode {
+                WindowMode::Windowed => WindowMode::BorderlessFullscreen,
+                _ => WindowMode::Windowed,
+            };
+        }
+    }
+}
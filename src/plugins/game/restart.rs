@@ -8,6 +8,10 @@ use crate::plugins::entities::player::*;
 use crate::plugins::entities::terrain::*;
 use crate::GameState;
 
+/// Starts a new run right away, without waiting for the restart key.
+#[derive(Event, Debug, Default)]
+pub struct Restart;
+
 #[derive(Component)]
 struct RestartTimer {
     timer: Timer,
@@ -23,29 +27,32 @@ impl Default for RestartTimer {
 
 impl Plugin for GameRestartPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (Self::restart)
-                .run_if(in_state(GameState::Resumed))
-                .run_if(in_state(Being::Dead)),
-        )
-        .add_systems(
-            OnTransition {
-                from: Being::Dead,
-                to: Being::Alive,
-            },
-            (
-                BuildingsPlugin::setup,
-                EnvironmentPlugin::setup,
-                (PlayerPlugin::setup, PlayerPlugin::restart).chain(),
+        app.add_event::<Restart>()
+            .add_systems(
+                Update,
+                (Self::restart)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(Being::Dead)),
+            )
+            .add_systems(
+                OnTransition {
+                    from: Being::Dead,
+                    to: Being::Alive,
+                },
+                (
+                    FloatingOriginPlugin::recenter,
+                    BuildingsPlugin::setup,
+                    EnvironmentPlugin::setup,
+                    (PlayerPlugin::setup, PlayerPlugin::restart).chain(),
+                )
+                    .chain(),
             )
-                .chain(),
-        )
-        .add_systems(OnEnter(Being::Dead), Self::setup_restart_timer);
+            .add_systems(OnEnter(Being::Dead), Self::setup_restart_timer);
     }
 }
 
 impl GameRestartPlugin {
+    #[allow(clippy::too_many_arguments)]
     fn restart(
         mut commands: Commands,
         mut next_being: ResMut<NextState<Being>>,
@@ -54,6 +61,7 @@ impl GameRestartPlugin {
         mut restart_timer: Query<(Entity, &mut RestartTimer)>,
         time: Res<Time>,
         input: Res<ButtonInput<KeyCode>>,
+        mut requested: EventReader<Restart>,
     ) {
         let Ok((restart_timer_entity, mut restart_timer)) = restart_timer.get_single_mut() else {
             return;
@@ -61,12 +69,14 @@ impl GameRestartPlugin {
 
         let tick = restart_timer.timer.tick(time.delta());
 
-        if tick.finished() && input.just_pressed(KeyCode::KeyR) {
+        let requested = requested.read().count() > 0;
+
+        if (tick.finished() && input.just_pressed(KeyCode::KeyR)) || requested {
             for platform in platforms_query.iter() {
                 commands.entity(platform).despawn_recursive();
             }
 
-            if let Ok(player) = player_query.get_single() {
+            for player in player_query.iter() {
                 commands.entity(player).despawn_recursive();
             }
 
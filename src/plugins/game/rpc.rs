@@ -5,15 +5,19 @@ use discord_rich_presence::{
 };
 use glib::{ DISCORD_LARGE_IMAGE, DISCORD_STATE};
 
+use super::locale::Localization;
+
 #[derive(Resource)]
 pub struct DiscordRPC {
     client: DiscordIpcClient,
+    connected: bool,
 }
 
 impl Default for DiscordRPC {
     fn default() -> Self {
         Self {
             client: DiscordIpcClient::new(env!("DISCORD_APP_ID")).unwrap(),
+            connected: false,
         }
     }
 }
@@ -23,26 +27,41 @@ pub struct RPCPlugin;
 impl Plugin for RPCPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DiscordRPC>()
-            .add_systems(PreStartup, Self::setup);
+            .add_systems(PreStartup, Self::setup)
+            .add_systems(
+                Update,
+                Self::update_activity.run_if(resource_changed::<Localization>),
+            );
     }
 }
 
 impl RPCPlugin {
     fn setup(mut rpc: ResMut<DiscordRPC>) {
-        if rpc.client.connect().is_ok() {
-            let payload = activity::Activity::new()
-                .details("Komboter is not dying, not today.")
-                .assets(
-                    Assets::new()
-                        .large_text(DISCORD_STATE)
-                        .large_image(DISCORD_LARGE_IMAGE),
-                )
-                .buttons(vec![
-                    Button::new("Play", env!("CARGO_PKG_HOMEPAGE")),
-                    Button::new("BSoD", "https://www.youtube.com/watch?v=njFw1NOAu3s"),
-                ]);
-
-            rpc.client.set_activity(payload).unwrap();
+        rpc.connected = rpc.client.connect().is_ok();
+    }
+
+    /// Sets the activity again whenever the language changes so the details follow it.
+    fn update_activity(mut rpc: ResMut<DiscordRPC>, localization: Res<Localization>) {
+        // nothing to show until the string tables are loaded
+        if !rpc.connected || localization.language().is_empty() {
+            return;
+        }
+
+        let details = localization.get("discord-details");
+        let payload = activity::Activity::new()
+            .details(&details)
+            .assets(
+                Assets::new()
+                    .large_text(DISCORD_STATE)
+                    .large_image(DISCORD_LARGE_IMAGE),
+            )
+            .buttons(vec![
+                Button::new("Play", env!("CARGO_PKG_HOMEPAGE")),
+                Button::new("BSoD", "https://www.youtube.com/watch?v=njFw1NOAu3s"),
+            ]);
+
+        if let Err(err) = rpc.client.set_activity(payload) {
+            warn!("could not update the discord activity: {err}");
         }
     }
 }
@@ -0,0 +1,149 @@
+use std::{fs, path::PathBuf};
+
+use bevy::prelude::*;
+use glib::utils;
+use serde::{Deserialize, Serialize};
+
+use super::locale::DEFAULT_LANGUAGE;
+
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master: f64,
+    pub music: f64,
+    pub ambience: f64,
+    pub sfx: f64,
+    pub ui: f64,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 1.0,
+            ambience: 1.0,
+            sfx: 1.0,
+            ui: 1.0,
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SkinSettings {
+    pub sheet: String,
+    pub palette: String,
+}
+
+impl Default for SkinSettings {
+    fn default() -> Self {
+        Self {
+            sheet: String::from("default"),
+            palette: String::from("original"),
+        }
+    }
+}
+
+#[derive(Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// no camera shake, zoom, bloom or pulsing and a slower parallax
+    pub reduced_motion: bool,
+    /// outlines the top of every platform
+    pub high_contrast: bool,
+    /// draws the memory bar with colors that stay apart for every kind of color blindness
+    pub colorblind: bool,
+    /// relative speed of the virtual clock
+    pub game_speed: f32,
+    /// jumps on its own right before the edge of a platform
+    pub auto_jump: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            reduced_motion: false,
+            high_contrast: false,
+            colorblind: false,
+            game_speed: 1.0,
+            auto_jump: false,
+        }
+    }
+}
+
+/// User preferences, loaded once at startup and written back whenever they change.
+#[derive(Resource, Reflect, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct Settings {
+    pub audio: AudioSettings,
+    pub skin: SkinSettings,
+    pub accessibility: AccessibilitySettings,
+    pub language: String,
+    pub tutorial_completed: bool,
+    /// versus runs give each player half of the window instead of sharing the camera
+    pub split_screen: bool,
+    /// records every run on disk for balancing, never sent anywhere
+    pub telemetry: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            audio: AudioSettings::default(),
+            skin: SkinSettings::default(),
+            accessibility: AccessibilitySettings::default(),
+            language: String::from(DEFAULT_LANGUAGE),
+            tutorial_completed: false,
+            split_screen: false,
+            telemetry: false,
+        }
+    }
+}
+
+impl Settings {
+    fn path() -> PathBuf {
+        utils::data_dir().join(SETTINGS_FILE)
+    }
+
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let path = Self::path();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+}
+
+pub struct GameSettingsPlugin;
+
+impl Plugin for GameSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .add_systems(Update, Self::save.run_if(resource_changed::<Settings>))
+            .register_type::<Settings>();
+    }
+}
+
+impl GameSettingsPlugin {
+    fn save(settings: Res<Settings>) {
+        if settings.is_added() {
+            return;
+        }
+
+        if let Err(err) = settings.save() {
+            warn!("failed to save settings: {err}");
+        }
+    }
+}
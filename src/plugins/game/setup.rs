@@ -1,13 +1,13 @@
 use bevy::{
     prelude::*,
-    window::{PresentMode, WindowMode, WindowResolution},
+    window::{PresentMode, WindowMode, WindowResizeConstraints, WindowResolution},
 };
 use bevy_embedded_assets::EmbeddedAssetPlugin;
 use bevy_kira_audio::prelude::*;
 use bevy_rapier2d::prelude::*;
 use bevy_tweening::TweeningPlugin;
 
-use super::prelude::Responsive;
+use super::prelude::GameResolutionPlugin;
 use crate::{
     plugins::{debug::DebugPlugin, entities::terrain::Platform},
     GameGroundCheckPlugin, Ground,
@@ -22,7 +22,17 @@ impl Plugin for GameEssentialsPlugin {
 
         app.insert_resource(ClearColor(WORLD_BACKGROUND_COLOR))
             .insert_resource(Msaa::Off)
-            .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0))
+            // stepped once per gameplay tick, in lockstep with the systems driving it
+            .insert_resource(RapierConfiguration {
+                timestep_mode: TimestepMode::Fixed {
+                    dt: SIMULATION_TIMESTEP,
+                    substeps: 1,
+                },
+                ..RapierConfiguration::new(100.0)
+            })
+            .add_plugins(
+                RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0).in_fixed_schedule(),
+            )
             .add_plugins(GameGroundCheckPlugin::<Ground>::default())
             .add_plugins((
                 DefaultPlugins
@@ -30,13 +40,15 @@ impl Plugin for GameEssentialsPlugin {
                         primary_window: Some(Window {
                             name: Some(APP_WINDOW_NAME.into()),
                             title: APP_WINDOW_NAME.into(),
-                            resolution: WindowResolution::new(
-                                APP_WINDOW_MIN_WIDTH,
-                                APP_WINDOW_MIN_HEIGHT,
-                            ),
+                            resolution: WindowResolution::new(APP_WINDOW_WIDTH, APP_WINDOW_HEIGHT),
+                            resize_constraints: WindowResizeConstraints {
+                                min_width: APP_WINDOW_MIN_WIDTH,
+                                min_height: APP_WINDOW_MIN_HEIGHT,
+                                ..Default::default()
+                            },
                             present_mode: PresentMode::AutoVsync,
                             mode: WindowMode::BorderlessFullscreen,
-                            resizable: false,
+                            resizable: true,
                             ..Default::default()
                         }),
                         ..Default::default()
@@ -46,7 +58,7 @@ impl Plugin for GameEssentialsPlugin {
                 AudioPlugin,
             ))
             .add_plugins(TweeningPlugin)
-            .add_systems(PostStartup, Self::rescale_sprites);
+            .add_plugins(GameResolutionPlugin);
 
         #[cfg(debug_assertions)]
         {
@@ -54,13 +66,3 @@ impl Plugin for GameEssentialsPlugin {
         }
     }
 }
-
-impl GameEssentialsPlugin {
-    fn rescale_sprites(mut query: Query<&mut Transform, With<Responsive>>, window: Query<&Window>) {
-        let window = window.single();
-
-        for mut transform in query.iter_mut() {
-            utils::rescale(&mut transform, window.width(), window.height());
-        }
-    }
-}
@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+use rand::Rng;
+
+use super::{
+    dialog::DialogBlip,
+    milestones::{MarkerKind, MilestonePassed},
+    sounds::{ChannelLevel, Sfx, Ui},
+};
+use crate::{
+    plugins::entities::player::{Being, Collected, Jumped, Landed, Memory, Milestone, Player},
+    AudioAssets, GameAssetsState, GameState, Settings,
+};
+use glib::*;
+
+pub struct GameSfxPlugin;
+
+impl Plugin for GameSfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                Self::on_jumped,
+                Self::on_landed,
+                Self::on_collected,
+                Self::on_milestone,
+                Self::on_milestone_passed,
+                Self::on_dialog_blip,
+                Self::memory_warning.run_if(in_state(Being::Alive)),
+            )
+                .run_if(in_state(GameAssetsState::Loaded))
+                .run_if(in_state(GameState::Resumed)),
+        )
+        .add_systems(OnEnter(GameState::Resumed), Self::on_start);
+    }
+}
+
+impl GameSfxPlugin {
+    /// random playback rate around 1.0 so repeated sounds don't all sound the same
+    fn pitch() -> f64 {
+        1.0 + rand::thread_rng().gen_range(-SFX_PITCH_VARIATION..=SFX_PITCH_VARIATION)
+    }
+
+    fn on_start(assets: Res<AudioAssets>, ui: Res<AudioChannel<Ui>>, settings: Res<Settings>) {
+        ui.play(assets.select.clone())
+            .with_volume(0.4 * Ui::level(&settings.audio));
+    }
+
+    fn on_jumped(
+        mut jumps: EventReader<Jumped>,
+        assets: Res<AudioAssets>,
+        sfx: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
+    ) {
+        for _ in jumps.read() {
+            sfx.play(assets.jmup.clone())
+                .with_playback_rate(Self::pitch())
+                .with_volume(0.4 * Sfx::level(&settings.audio));
+        }
+    }
+
+    fn on_landed(
+        mut landings: EventReader<Landed>,
+        assets: Res<AudioAssets>,
+        sfx: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
+    ) {
+        for landing in landings.read() {
+            let volume = (landing.impact / PLAYER_MAX_VELOCITY_X).clamp(0.2, 0.8);
+
+            sfx.play(assets.land.clone())
+                .with_playback_rate(Self::pitch())
+                .with_volume(volume as f64 * Sfx::level(&settings.audio));
+        }
+    }
+
+    fn on_collected(
+        mut pickups: EventReader<Collected>,
+        assets: Res<AudioAssets>,
+        sfx: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
+    ) {
+        for _ in pickups.read() {
+            sfx.play(assets.pickup.clone())
+                .with_playback_rate(Self::pitch())
+                .with_volume(0.5 * Sfx::level(&settings.audio));
+        }
+    }
+
+    fn on_milestone(
+        mut milestones: EventReader<Milestone>,
+        assets: Res<AudioAssets>,
+        sfx: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
+    ) {
+        for _ in milestones.read() {
+            sfx.play(assets.milestone.clone())
+                .with_volume(0.5 * Sfx::level(&settings.audio));
+        }
+    }
+
+    /// The previous best gets a louder sting than the regular markers.
+    fn on_milestone_passed(
+        mut passed: EventReader<MilestonePassed>,
+        assets: Res<AudioAssets>,
+        sfx: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
+    ) {
+        for event in passed.read() {
+            let volume = match event.kind {
+                MarkerKind::Best => 0.7,
+                MarkerKind::Meters(_) => 0.45,
+            };
+
+            sfx.play(assets.sting.clone())
+                .with_volume(volume * Sfx::level(&settings.audio));
+        }
+    }
+
+    fn on_dialog_blip(
+        mut blips: EventReader<DialogBlip>,
+        assets: Res<AudioAssets>,
+        ui: Res<AudioChannel<Ui>>,
+        settings: Res<Settings>,
+    ) {
+        for blip in blips.read() {
+            ui.play(assets.blip.clone())
+                .with_playback_rate(blip.pitch)
+                .with_volume(0.25 * Ui::level(&settings.audio));
+        }
+    }
+
+    /// beeps faster and faster as the memory of any runner gets closer to running out
+    fn memory_warning(
+        memory: Query<&Memory, With<Player>>,
+        assets: Res<AudioAssets>,
+        ui: Res<AudioChannel<Ui>>,
+        settings: Res<Settings>,
+        mut timer: Local<Timer>,
+        time: Res<Time>,
+    ) {
+        let Some(lowest) = memory.iter().map(Memory::value).reduce(f32::min) else {
+            return;
+        };
+
+        if lowest >= PLAYER_MEMORY_WARNING {
+            timer.reset();
+            return;
+        }
+
+        let urgency = (lowest / PLAYER_MEMORY_WARNING).max(0.25);
+        timer.set_duration(Duration::from_secs_f32(
+            SFX_MEMORY_BEEP_INTERVAL.as_secs_f32() * urgency,
+        ));
+
+        if timer.tick(time.delta()).just_finished() {
+            ui.play(assets.beep.clone())
+                .with_volume(0.4 * Ui::level(&settings.audio));
+            timer.reset();
+        }
+    }
+}
@@ -1,92 +1,457 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
-use bevy_kira_audio::prelude::*;
+use bevy_kira_audio::prelude::{AudioSource, *};
 
-use crate::{plugins::entities::player::Being, AudioAssets, GameAssetsState, GameState};
+use crate::{
+    plugins::debug::{arg, CommandResult, ConsoleApp},
+    plugins::entities::player::{AuxiliaryVelocity, Being, Memory, MovementType, Player},
+    AudioAssets, AudioSettings, GameAssetsState, GameState, Settings,
+};
+use glib::*;
 
+/// The soundtrack is played as two stems kept in lockstep, the second one being a low
+/// passed copy of the first that gets faded in when the memory is about to run out.
 #[derive(Resource)]
-struct InstanceHandle(Handle<AudioInstance>);
+struct InstanceHandles {
+    original: Handle<AudioInstance>,
+    muffled: Handle<AudioInstance>,
+    /// shares of the soundtrack volume the stems are mixed at
+    gains: (f64, f64),
+}
+
+impl InstanceHandles {
+    /// Volume of a stem mixed at `gain`, under the soundtrack volume and the music level.
+    fn volume(gain: f64, audio: &AudioSettings) -> f64 {
+        gain * SOUNDTRACK_VOLUME * Music::level(audio)
+    }
+}
+
+#[derive(Resource)]
+struct AmbienceHandle(Handle<AudioInstance>);
+
+#[derive(Resource)]
+struct MuffledSoundtrack(Handle<AudioSource>);
+
+// audio channels, each one gets its own volume in the settings
+#[derive(Resource)]
+pub struct Music;
+
+#[derive(Resource)]
+pub struct Ambience;
+
+#[derive(Resource)]
+pub struct Sfx;
+
+#[derive(Resource)]
+pub struct Ui;
+
+/// Share of the full volume the settings leave a channel. Sounds get played at their
+/// own volume times it, setting the volume of a channel would override the volume of
+/// every sound playing on it.
+pub trait ChannelLevel: Resource {
+    fn level(audio: &AudioSettings) -> f64;
+}
+
+impl ChannelLevel for Music {
+    fn level(audio: &AudioSettings) -> f64 {
+        audio.master * audio.music
+    }
+}
+
+impl ChannelLevel for Ambience {
+    fn level(audio: &AudioSettings) -> f64 {
+        audio.master * audio.ambience
+    }
+}
+
+impl ChannelLevel for Sfx {
+    fn level(audio: &AudioSettings) -> f64 {
+        audio.master * audio.sfx
+    }
+}
+
+impl ChannelLevel for Ui {
+    fn level(audio: &AudioSettings) -> f64 {
+        audio.master * audio.ui
+    }
+}
+
+#[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum MusicState {
+    #[default]
+    Silent,
+    Walking,
+    Running,
+    Critical,
+    Dead,
+}
 
 const LOOP_POSITION: (f64, f64) = (38.392, 44.781);
+const AMBIENCE_VOLUME: f64 = 0.3;
+const SOUNDTRACK_VOLUME: f64 = 0.3;
+// the loop region is four bars long
+const BEAT: f64 = (LOOP_POSITION.1 - LOOP_POSITION.0) / 16.0;
 
 pub struct GameSoundTrack;
 
 impl Plugin for GameSoundTrack {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            OnEnter(GameState::Resumed),
-            Self::play_background_soundtrack,
-        )
-        .add_systems(
-            Update,
-            Self::control_background_audio.run_if(resource_exists::<InstanceHandle>),
-        )
-        .add_systems(
-            OnEnter(GameAssetsState::Loaded),
-            Self::play_background_ambience,
-        );
+        app.add_audio_channel::<Music>()
+            .add_audio_channel::<Ambience>()
+            .add_audio_channel::<Sfx>()
+            .add_audio_channel::<Ui>();
+
+        app.init_state::<MusicState>()
+            .add_systems(
+                OnEnter(GameState::Resumed),
+                Self::play_background_soundtrack,
+            )
+            .add_systems(
+                Update,
+                (Self::direct, Self::tempo)
+                    .run_if(resource_exists::<InstanceHandles>)
+                    .run_if(in_state(GameState::Resumed)),
+            )
+            .add_systems(
+                OnEnter(GameAssetsState::Loaded),
+                (Self::play_background_ambience, Self::muffle_soundtrack),
+            )
+            .add_systems(OnEnter(MusicState::Walking), Self::mix::<85, 0>)
+            .add_systems(OnEnter(MusicState::Running), Self::mix::<100, 0>)
+            .add_systems(OnEnter(MusicState::Critical), Self::mix::<35, 115>)
+            .add_systems(OnEnter(MusicState::Dead), Self::pause)
+            .add_systems(OnExit(MusicState::Dead), Self::resume)
+            .add_systems(Update, Self::retune.run_if(resource_changed::<Settings>))
+            .register_type::<MusicState>()
+            .console_command(
+                "volume",
+                "volume <master|music|ambience|sfx|ui> <percent>",
+                &["master", "music", "ambience", "sfx", "ui"],
+                Self::volume_command,
+            );
     }
 }
 
 impl GameSoundTrack {
-    fn play_background_ambience(audio_assets: Res<AudioAssets>, audio: Res<Audio>) {
-        audio
-            .play(audio_assets.ambience.clone())
-            .with_volume(0.3)
-            .looped();
+    /// Brings the looping sounds to the new levels, the short ones are left to finish
+    /// at the level they started with.
+    fn retune(
+        settings: Res<Settings>,
+        handles: Option<Res<InstanceHandles>>,
+        ambience: Option<Res<AmbienceHandle>>,
+        mut audio_instances: ResMut<Assets<AudioInstance>>,
+    ) {
+        let mut volumes = Vec::new();
+
+        if let Some(handles) = handles {
+            let (original, muffled) = handles.gains;
+            volumes.push((
+                handles.original.clone(),
+                InstanceHandles::volume(original, &settings.audio),
+            ));
+            volumes.push((
+                handles.muffled.clone(),
+                InstanceHandles::volume(muffled, &settings.audio),
+            ));
+        }
+
+        if let Some(ambience) = ambience {
+            let level = Ambience::level(&settings.audio);
+            volumes.push((ambience.0.clone(), AMBIENCE_VOLUME * level));
+        }
+
+        for (handle, volume) in volumes {
+            if let Some(instance) = audio_instances.get_mut(&handle) {
+                instance.set_volume(Volume::Amplitude(volume), AudioTween::default());
+            }
+        }
     }
 
-    fn play_background_soundtrack(
+    fn volume_command(In(args): In<Vec<String>>, mut settings: ResMut<Settings>) -> CommandResult {
+        let usage = "volume <master|music|ambience|sfx|ui> <percent>";
+        let percent: f64 = arg(&args, 1, usage)?;
+        let volumes = &mut settings.audio;
+
+        let volume = match args[0].as_str() {
+            "master" => &mut volumes.master,
+            "music" => &mut volumes.music,
+            "ambience" => &mut volumes.ambience,
+            "sfx" => &mut volumes.sfx,
+            "ui" => &mut volumes.ui,
+            _ => return Err(format!("usage: {usage}")),
+        };
+        *volume = (percent / 100.0).clamp(0.0, 1.0);
+
+        Ok(format!("{} volume at {percent}%", args[0]))
+    }
+
+    fn play_background_ambience(
         mut commands: Commands,
         audio_assets: Res<AudioAssets>,
-        audio: Res<Audio>,
+        audio: Res<AudioChannel<Ambience>>,
+        settings: Res<Settings>,
     ) {
         let handle = audio
-            .play(audio_assets.original.clone())
-            .fade_in(AudioTween::new(
-                Duration::from_secs(5),
-                AudioEasing::OutPowi(2),
-            ))
-            .loop_from(LOOP_POSITION.0)
-            .loop_until(LOOP_POSITION.1)
-            .with_volume(0.3)
+            .play(audio_assets.ambience.clone())
+            .with_volume(AMBIENCE_VOLUME * Ambience::level(&settings.audio))
+            .looped()
             .handle();
 
-        commands.insert_resource(InstanceHandle(handle));
+        commands.insert_resource(AmbienceHandle(handle));
     }
 
-    fn control_background_audio(
-        background_audio: Res<InstanceHandle>,
-        mut being_events: EventReader<StateTransitionEvent<Being>>,
+    /// Runs the soundtrack through a one pole low pass filter, kira tracks and their
+    /// effects aren't reachable from `bevy_kira_audio` so the filtered stem is baked once.
+    fn muffle_soundtrack(
+        mut commands: Commands,
+        audio_assets: Res<AudioAssets>,
+        mut sources: ResMut<Assets<AudioSource>>,
+    ) {
+        let Some(original) = sources.get(&audio_assets.original) else {
+            return;
+        };
+
+        let sound = &original.sound;
+        let rc = 1.0 / (2.0 * std::f32::consts::PI * MUSIC_LOW_PASS_CUTOFF);
+        let dt = 1.0 / sound.sample_rate as f32;
+        let alpha = dt / (rc + dt);
+
+        let mut prev = Frame::ZERO;
+        let frames = sound
+            .frames
+            .iter()
+            .map(|frame| {
+                prev = prev + (*frame - prev) * alpha;
+                prev
+            })
+            .collect::<Vec<Frame>>();
+
+        let muffled = AudioSource {
+            sound: StaticSoundData {
+                sample_rate: sound.sample_rate,
+                frames: frames.into(),
+                settings: sound.settings,
+            },
+        };
+
+        commands.insert_resource(MuffledSoundtrack(sources.add(muffled)));
+    }
+
+    fn play_background_soundtrack(
+        mut commands: Commands,
+        audio_assets: Res<AudioAssets>,
+        muffled: Option<Res<MuffledSoundtrack>>,
+        audio: Res<AudioChannel<Music>>,
+        settings: Res<Settings>,
+    ) {
+        let play = |source: Handle<AudioSource>, gain: f64| {
+            audio
+                .play(source)
+                .fade_in(AudioTween::new(
+                    Duration::from_secs(5),
+                    AudioEasing::OutPowi(2),
+                ))
+                .loop_from(LOOP_POSITION.0)
+                .loop_until(LOOP_POSITION.1)
+                .with_volume(InstanceHandles::volume(gain, &settings.audio))
+                .handle()
+        };
+
+        let gains = (1.0, 0.0);
+        let original = play(audio_assets.original.clone(), gains.0);
+        let muffled = play(
+            muffled
+                .map(|muffled| muffled.0.clone())
+                .unwrap_or_else(|| audio_assets.original.clone()),
+            gains.1,
+        );
+
+        commands.insert_resource(InstanceHandles {
+            original,
+            muffled,
+            gains,
+        });
+    }
+
+    /// Picks the state the music should be in and switches to it on the next beat, dying
+    /// and respawning cut in right away.
+    #[allow(clippy::too_many_arguments)]
+    fn direct(
+        handles: Res<InstanceHandles>,
+        audio_instances: Res<Assets<AudioInstance>>,
+        music: Res<State<MusicState>>,
+        mut next_music: ResMut<NextState<MusicState>>,
+        being: Res<State<Being>>,
+        movement: Res<State<MovementType>>,
+        memory: Query<&Memory, With<Player>>,
+        mut last_beat: Local<i64>,
+    ) {
+        let desired = if *being.get() == Being::Dead {
+            MusicState::Dead
+        } else if memory
+            .iter()
+            .any(|memory| memory.value() < PLAYER_MEMORY_CRITICAL)
+        {
+            MusicState::Critical
+        } else if *movement.get() == MovementType::Walking {
+            MusicState::Walking
+        } else {
+            MusicState::Running
+        };
+
+        let Some(instance) = audio_instances.get(&handles.original) else {
+            return;
+        };
+
+        let beat = match instance.state() {
+            PlaybackState::Playing { position } => ((position - LOOP_POSITION.0) / BEAT).floor(),
+            _ => *last_beat as f64,
+        } as i64;
+
+        let on_beat = beat != *last_beat;
+        *last_beat = beat;
+
+        let current = *music.get();
+        let immediate = desired == MusicState::Dead || current == MusicState::Dead;
+
+        if desired != current && (on_beat || immediate) {
+            next_music.set(desired);
+        }
+    }
+
+    /// Speeds the soundtrack up along with the fastest runner.
+    fn tempo(
+        handles: Res<InstanceHandles>,
+        mut audio_instances: ResMut<Assets<AudioInstance>>,
+        music: Res<State<MusicState>>,
+        velocity: Query<&AuxiliaryVelocity, With<Player>>,
+        mut last_rate: Local<f64>,
+    ) {
+        let fastest = velocity
+            .iter()
+            .map(|velocity| velocity.value.x)
+            .reduce(f32::max);
+
+        let rate = match (music.get(), fastest) {
+            (MusicState::Running | MusicState::Critical, Some(velocity)) => {
+                1.0 + (velocity / PLAYER_MAX_VELOCITY_X) as f64 * MUSIC_MAX_SPEEDUP
+            }
+            _ => 1.0,
+        };
+
+        if (rate - *last_rate).abs() < 0.005 {
+            return;
+        }
+
+        *last_rate = rate;
+
+        for handle in [&handles.original, &handles.muffled] {
+            if let Some(instance) = audio_instances.get_mut(handle) {
+                instance.set_playback_rate(
+                    rate,
+                    AudioTween::new(Duration::from_millis(100), AudioEasing::Linear),
+                );
+            }
+        }
+    }
+
+    /// Crossfades the stems over a beat, volumes are given in percents of the soundtrack
+    /// volume.
+    fn mix<const ORIGINAL: u8, const MUFFLED: u8>(
+        handles: Option<ResMut<InstanceHandles>>,
+        mut audio_instances: ResMut<Assets<AudioInstance>>,
+        settings: Res<Settings>,
+    ) {
+        let Some(mut handles) = handles else {
+            return;
+        };
+
+        let (original, muffled) = (ORIGINAL as f64 / 100.0, MUFFLED as f64 / 100.0);
+        handles.gains = (original, muffled);
+
+        for (handle, gain) in [(&handles.original, original), (&handles.muffled, muffled)] {
+            if let Some(instance) = audio_instances.get_mut(handle) {
+                instance.set_volume(
+                    Volume::Amplitude(InstanceHandles::volume(gain, &settings.audio)),
+                    AudioTween::new(Duration::from_secs_f64(BEAT), AudioEasing::InOutPowi(2)),
+                );
+            }
+        }
+    }
+
+    fn pause(
+        handles: Option<Res<InstanceHandles>>,
         mut audio_instances: ResMut<Assets<AudioInstance>>,
         audio_assets: Res<AudioAssets>,
-        audio: Res<Audio>,
+        audio: Res<AudioChannel<Sfx>>,
+        settings: Res<Settings>,
     ) {
-        if let Some(instance) = audio_instances.get_mut(&background_audio.0) {
-            for ev in being_events.read() {
-                match instance.state() {
-                    PlaybackState::Playing { position: _ } => {
-                        if ev.before == Being::Alive && ev.after == Being::Dead {
-                            instance.pause(AudioTween::new(
-                                Duration::from_secs_f32(0.0),
-                                AudioEasing::OutPowi(0),
-                            ));
-                            audio.play(audio_assets.death.clone()).with_volume(0.5);
-                        }
-                    }
-                    PlaybackState::Paused { position: _ } => {
-                        if ev.before == Being::Dead && ev.after == Being::Alive {
-                            instance.seek_to(38.392);
-                            instance.resume(AudioTween::new(
-                                Duration::from_secs_f32(0.2),
-                                AudioEasing::OutPowi(1),
-                            ));
-                        }
-                    }
-                    _ => {}
-                }
+        let Some(handles) = handles else {
+            return;
+        };
+
+        for handle in [&handles.original, &handles.muffled] {
+            if let Some(instance) = audio_instances.get_mut(handle) {
+                instance.pause(AudioTween::new(
+                    Duration::from_secs_f32(0.0),
+                    AudioEasing::OutPowi(0),
+                ));
             }
         }
+
+        audio
+            .play(audio_assets.death.clone())
+            .with_volume(0.5 * Sfx::level(&settings.audio));
+    }
+
+    fn resume(
+        handles: Option<Res<InstanceHandles>>,
+        mut audio_instances: ResMut<Assets<AudioInstance>>,
+    ) {
+        let Some(handles) = handles else {
+            return;
+        };
+
+        for handle in [&handles.original, &handles.muffled] {
+            if let Some(instance) = audio_instances.get_mut(handle) {
+                instance.seek_to(LOOP_POSITION.0);
+                instance.resume(AudioTween::new(
+                    Duration::from_secs_f32(0.2),
+                    AudioEasing::OutPowi(1),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_sounds {
+    use super::{InstanceHandles, SOUNDTRACK_VOLUME};
+    use crate::AudioSettings;
+
+    #[test]
+    fn mix_follows_the_music_level() {
+        let full = AudioSettings::default();
+        let quiet = AudioSettings {
+            master: 0.5,
+            music: 0.4,
+            ..Default::default()
+        };
+
+        assert_eq!(InstanceHandles::volume(1.0, &full), SOUNDTRACK_VOLUME);
+        assert!(
+            (InstanceHandles::volume(0.35, &quiet) - 0.35 * SOUNDTRACK_VOLUME * 0.2).abs() < 1e-9
+        );
+        assert_eq!(
+            InstanceHandles::volume(
+                1.0,
+                &AudioSettings {
+                    master: 0.0,
+                    ..full
+                }
+            ),
+            0.0
+        );
     }
 }
@@ -0,0 +1,183 @@
+use std::{path::PathBuf, time::SystemTime};
+
+use bevy::prelude::*;
+use glib::{
+    telemetry::{self, RunRecord},
+    TELEMETRY_SAMPLE_INTERVAL,
+};
+
+use crate::{
+    plugins::{
+        debug::{CommandResult, ConsoleApp},
+        entities::{
+            player::{
+                Being, Collected, Died, Distance, Jumped, Memory, Primary, Runner, ScoreBreakdown,
+            },
+            terrain::{Platform, WorldRng},
+        },
+    },
+    Difficulty, GameState, Settings,
+};
+
+/// The run being recorded, written out once it ends.
+#[derive(Resource)]
+struct Recording {
+    run: RunRecord,
+    /// runner of the primary player, the one being recorded
+    runner: usize,
+    sample: Timer,
+    /// every session gets its own file
+    file: PathBuf,
+}
+
+impl Default for Recording {
+    fn default() -> Self {
+        let started = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Self {
+            run: RunRecord::default(),
+            runner: 0,
+            sample: Timer::from_seconds(TELEMETRY_SAMPLE_INTERVAL, TimerMode::Repeating),
+            file: telemetry::dir().join(format!("runs-{started}.jsonl")),
+        }
+    }
+}
+
+/// Opt-in recorder writing a line per run to the telemetry folder, the `telemetry`
+/// binary turns those into histograms for balancing. Nothing leaves the machine.
+pub struct GameTelemetryPlugin;
+
+impl Plugin for GameTelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Recording>()
+            .add_systems(OnEnter(Being::Alive), Self::reset)
+            .add_systems(
+                Update,
+                (Self::track, Self::gaps)
+                    .run_if(Self::enabled)
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(in_state(Being::Alive)),
+            )
+            .add_systems(OnEnter(Being::Dead), Self::write.run_if(Self::enabled))
+            .console_command(
+                "telemetry",
+                "telemetry <on|off>",
+                &["on", "off"],
+                Self::telemetry_command,
+            );
+    }
+}
+
+impl GameTelemetryPlugin {
+    fn enabled(settings: Res<Settings>) -> bool {
+        settings.telemetry
+    }
+
+    fn reset(mut recording: ResMut<Recording>) {
+        recording.run = RunRecord::default();
+        recording.sample.reset();
+    }
+
+    fn track(
+        player: Query<(Entity, &Runner, &Distance, &Memory), With<Primary>>,
+        mut jumped: EventReader<Jumped>,
+        mut collected: EventReader<Collected>,
+        mut died: EventReader<Died>,
+        mut recording: ResMut<Recording>,
+        time: Res<Time>,
+    ) {
+        // the runner is already gone on the frame it dies
+        let runner = recording.runner;
+        if let Some(death) = died.read().find(|death| death.runner == runner) {
+            recording.run.death_cause = death.cause.key().to_owned();
+        }
+
+        let Ok((entity, runner, distance, memory)) = player.get_single() else {
+            return;
+        };
+
+        let run = &mut recording.run;
+        run.death_x = distance.value;
+        run.jumps += jumped.read().filter(|jump| jump.player == entity).count() as u32;
+        run.bytes += collected
+            .read()
+            .filter(|byte| byte.player == entity)
+            .count() as u32;
+
+        recording.runner = runner.0;
+
+        if recording.sample.tick(time.delta()).just_finished() {
+            recording.run.memory.push(memory.value());
+        }
+    }
+
+    /// Measures every new platform against the closest one standing behind it.
+    fn gaps(
+        added: Query<(&Platform, &Transform), Added<Platform>>,
+        platforms: Query<(&Platform, &Transform)>,
+        mut recording: ResMut<Recording>,
+    ) {
+        for (platform, transform) in added.iter() {
+            let left = transform.translation.x - platform.width / 2.0;
+
+            let behind = platforms
+                .iter()
+                .map(|(platform, transform)| transform.translation.x + platform.width / 2.0)
+                .filter(|right| *right <= left)
+                .reduce(f32::max);
+
+            if let Some(right) = behind {
+                recording.run.gaps.push(left - right);
+            }
+        }
+    }
+
+    fn write(
+        mut recording: ResMut<Recording>,
+        mut died: EventReader<Died>,
+        rng: Res<WorldRng>,
+        difficulty: Res<Difficulty>,
+        breakdown: Res<ScoreBreakdown>,
+    ) {
+        let recording = &mut *recording;
+
+        // the deaths ending the run may never have reached `track`, and a runner that
+        // went out unseen earlier on gets the cause of whatever ended the run
+        let ending = died.read().collect::<Vec<_>>();
+        let death = match ending.iter().find(|death| death.runner == recording.runner) {
+            Some(death) => Some(death),
+            None if recording.run.death_cause.is_empty() => ending.first(),
+            None => None,
+        };
+        if let Some(death) = death {
+            recording.run.death_cause = death.cause.key().to_owned();
+        }
+
+        recording.run.seed = rng.1;
+        recording.run.difficulty = difficulty.0;
+        recording.run.score = breakdown.total();
+
+        if let Err(err) = recording.run.append(&recording.file) {
+            warn!("failed to record the run: {err}");
+        }
+    }
+
+    fn telemetry_command(
+        In(args): In<Vec<String>>,
+        mut settings: ResMut<Settings>,
+    ) -> CommandResult {
+        settings.telemetry = match args.first().map(String::as_str) {
+            Some("on") => true,
+            Some("off") => false,
+            _ => return Err("usage: telemetry <on|off>".into()),
+        };
+
+        Ok(match settings.telemetry {
+            true => format!("runs get recorded to {}", telemetry::dir().display()),
+            false => "runs are no longer recorded".into(),
+        })
+    }
+}
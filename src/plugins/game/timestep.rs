@@ -0,0 +1,102 @@
+use bevy::{app::RunFixedMainLoop, prelude::*, time::run_fixed_main_schedule};
+use bevy_rapier2d::prelude::*;
+
+use glib::SIMULATION_TICK_RATE;
+
+/// Gameplay systems running in `FixedUpdate`, ahead of the Rapier step that picks up
+/// whatever they changed. Everything a run depends on ticks here, frames only render.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameplaySet;
+
+/// Translation an entity had at the last two ticks, it gets drawn in between them
+/// so the movement stays smooth at any frame rate.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+pub struct Interpolated {
+    pub previous: Vec3,
+    pub current: Vec3,
+}
+
+impl Interpolated {
+    pub fn at(translation: Vec3) -> Self {
+        Self {
+            previous: translation,
+            current: translation,
+        }
+    }
+
+    /// `fraction` of the way from the previous tick to the current one.
+    pub fn lerp(&self, fraction: f32) -> Vec3 {
+        self.previous.lerp(self.current, fraction)
+    }
+
+    /// Moves both ticks, the entity jumps there instead of sliding across.
+    pub fn shift(&mut self, offset: Vec3) {
+        self.previous += offset;
+        self.current += offset;
+    }
+}
+
+pub struct GameTimestepPlugin;
+
+impl Plugin for GameTimestepPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Time::<Fixed>::from_hz(SIMULATION_TICK_RATE))
+            .configure_sets(FixedUpdate, GameplaySet.before(PhysicsSet::SyncBackend))
+            .add_systems(FixedFirst, Self::restore)
+            .add_systems(FixedPostUpdate, Self::record)
+            .add_systems(
+                RunFixedMainLoop,
+                Self::interpolate.after(run_fixed_main_schedule),
+            )
+            .register_type::<Interpolated>();
+    }
+}
+
+impl GameTimestepPlugin {
+    /// Puts back where the last tick left things, the simulation never sees the
+    /// rendered positions.
+    fn restore(mut query: Query<(&mut Transform, &Interpolated)>) {
+        for (mut transform, interpolated) in query.iter_mut() {
+            transform.translation = interpolated.current;
+        }
+    }
+
+    fn record(mut query: Query<(&Transform, &mut Interpolated)>) {
+        for (transform, mut interpolated) in query.iter_mut() {
+            interpolated.previous = interpolated.current;
+            interpolated.current = transform.translation;
+        }
+    }
+
+    /// Runs right after the ticks of the frame, whatever reads positions in `Update`
+    /// already sees what gets drawn.
+    fn interpolate(mut query: Query<(&mut Transform, &Interpolated)>, time: Res<Time<Fixed>>) {
+        let fraction = time.overstep_fraction();
+
+        for (mut transform, interpolated) in query.iter_mut() {
+            transform.translation = interpolated.lerp(fraction);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_timestep {
+    use super::*;
+
+    #[test]
+    fn shifting_keeps_the_rendered_position_in_step() {
+        let mut interpolated = Interpolated {
+            previous: Vec3::new(19_990.0, 40.0, 10.0),
+            current: Vec3::new(20_010.0, 42.0, 10.0),
+        };
+        let before = interpolated.lerp(0.25);
+
+        interpolated.shift(Vec3::new(-20_010.0, 0.0, 0.0));
+
+        assert_eq!(
+            interpolated.lerp(0.25),
+            before - Vec3::new(20_010.0, 0.0, 0.0)
+        );
+        assert_eq!(interpolated.lerp(1.0), Vec3::new(0.0, 42.0, 10.0));
+    }
+}
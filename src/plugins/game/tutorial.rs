@@ -0,0 +1,346 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::RapierConfiguration;
+
+use super::dialog::{Dialog, Line};
+use crate::{
+    plugins::entities::{
+        bytes::Byte,
+        player::{Collected, Jump, Landed, MovementType, PlayerGrounded, Primary},
+    },
+    FontsAssets, GameAssetsState, GameState, Grounded, Localization, Localized, Settings,
+};
+use glib::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TutorialStep {
+    Tap,
+    Hold,
+    Coyote,
+    Bytes,
+    Done,
+}
+
+impl TutorialStep {
+    /// Key of the line shown when the step begins, before its trigger point is reached.
+    fn intro(self) -> Option<&'static str> {
+        match self {
+            Self::Coyote => Some("tutorial-coyote-intro"),
+            Self::Done => Some("tutorial-done"),
+            _ => None,
+        }
+    }
+
+    /// Key of the line shown while the game is paused at the trigger point.
+    fn line(self) -> &'static str {
+        match self {
+            Self::Tap => "tutorial-tap",
+            Self::Hold => "tutorial-hold",
+            Self::Coyote => "tutorial-coyote",
+            Self::Bytes => "tutorial-bytes",
+            Self::Done => "",
+        }
+    }
+
+    /// Key resuming the game, the jump steps wait for the jump itself.
+    fn prompt(self) -> KeyCode {
+        match self {
+            Self::Bytes => KeyCode::Enter,
+            _ => KeyCode::Space,
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Tap => Self::Hold,
+            Self::Hold => Self::Coyote,
+            Self::Coyote => Self::Bytes,
+            Self::Bytes | Self::Done => Self::Done,
+        }
+    }
+}
+
+/// Present while the tutorial runs, `resolved` is set once the current step's trigger
+/// has been hit and the player went past its prompt.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Tutorial {
+    pub step: TutorialStep,
+    pub paused: bool,
+    pub resolved: bool,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Self {
+            step: TutorialStep::Tap,
+            paused: false,
+            resolved: false,
+        }
+    }
+}
+
+#[derive(Component)]
+struct KeyPrompt;
+
+pub struct GameTutorialPlugin;
+
+impl Plugin for GameTutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Resumed), Self::offer)
+            .add_systems(
+                Update,
+                (Self::trigger, Self::resume, Self::advance, Self::skip)
+                    .chain()
+                    .run_if(resource_exists::<Tutorial>)
+                    .run_if(in_state(GameAssetsState::Loaded))
+                    .run_if(in_state(GameState::Resumed)),
+            )
+            .register_type::<Tutorial>();
+    }
+}
+
+impl GameTutorialPlugin {
+    /// Starts the tutorial on the very first run only.
+    fn offer(mut commands: Commands, settings: Res<Settings>) {
+        if !settings.tutorial_completed {
+            commands.init_resource::<Tutorial>();
+        }
+    }
+
+    /// Stops everything driven by the virtual clock along with the physics.
+    fn freeze(time: &mut Time<Virtual>, rapier: &mut RapierConfiguration, frozen: bool) {
+        if frozen {
+            time.pause();
+        } else {
+            time.unpause();
+        }
+
+        rapier.physics_pipeline_active = !frozen;
+    }
+
+    fn say(dialog: &mut Query<&mut Dialog>, localization: &Localization, key: &str) {
+        for mut dialog in dialog.iter_mut() {
+            dialog.play(vec![Line::new(
+                localization.get(key),
+                TUTORIAL_LINE_DURATION,
+            )]);
+        }
+    }
+
+    /// Freezes the game once the player reaches the point the current step is about.
+    #[allow(clippy::too_many_arguments)]
+    fn trigger(
+        mut commands: Commands,
+        mut tutorial: ResMut<Tutorial>,
+        mut time: ResMut<Time<Virtual>>,
+        mut rapier: ResMut<RapierConfiguration>,
+        player: Query<(Entity, &Transform, &Jump), With<Primary>>,
+        grounded: Query<(&Grounded, &Parent), With<PlayerGrounded>>,
+        bytes: Query<&GlobalTransform, With<Byte>>,
+        mut dialog: Query<&mut Dialog>,
+        movement: Res<State<MovementType>>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        if tutorial.paused || tutorial.resolved || *movement.get() != MovementType::Running {
+            return;
+        }
+
+        let Ok((entity, transform, jump)) = player.get_single() else {
+            return;
+        };
+
+        let Some(grounded) = PlayerGrounded::of(&grounded, entity) else {
+            return;
+        };
+
+        let triggered = match tutorial.step {
+            TutorialStep::Tap | TutorialStep::Hold => grounded.value,
+            TutorialStep::Coyote => !grounded.value && !jump.rising && jump.coyote > 0.0,
+            TutorialStep::Bytes => bytes.iter().any(|byte| {
+                let distance = byte.translation().x - transform.translation.x;
+                (0.0..TUTORIAL_BYTE_DISTANCE).contains(&distance)
+            }),
+            TutorialStep::Done => false,
+        };
+
+        if !triggered {
+            return;
+        }
+
+        Self::freeze(&mut time, &mut rapier, true);
+        tutorial.paused = true;
+
+        Self::say(&mut dialog, &localization, tutorial.step.line());
+
+        let key = match tutorial.step.prompt() {
+            KeyCode::Enter => "key-enter",
+            _ => "key-space",
+        };
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    top: Val::Percent(40.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(Name::new("Key Prompt"))
+            .insert(KeyPrompt)
+            .with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            padding: UiRect::axes(Val::Px(18.0), Val::Px(10.0)),
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..Default::default()
+                        },
+                        border_color: Color::WHITE.into(),
+                        background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                        ..Default::default()
+                    })
+                    .with_children(|parent| {
+                        parent
+                            .spawn(TextBundle::from_section(
+                                localization.get(key),
+                                TextStyle {
+                                    font: fonts.vcr.clone(),
+                                    font_size: 42.0,
+                                    color: Color::WHITE,
+                                },
+                            ))
+                            .insert(Localized::new(key));
+                    });
+
+                parent
+                    .spawn(TextBundle::from_section(
+                        localization.get("tutorial-skip"),
+                        TextStyle {
+                            font: fonts.vcr.clone(),
+                            font_size: 18.0,
+                            color: Color::WHITE,
+                        },
+                    ))
+                    .insert(Localized::new("tutorial-skip"));
+            });
+    }
+
+    /// Lets the game go on once the prompted key is pressed, the press itself still
+    /// reaches the player so jumping out of a pause works as expected.
+    fn resume(
+        mut commands: Commands,
+        mut tutorial: ResMut<Tutorial>,
+        mut time: ResMut<Time<Virtual>>,
+        mut rapier: ResMut<RapierConfiguration>,
+        prompts: Query<Entity, With<KeyPrompt>>,
+        input: Res<ButtonInput<KeyCode>>,
+    ) {
+        if !tutorial.paused || !input.just_pressed(tutorial.step.prompt()) {
+            return;
+        }
+
+        for entity in prompts.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        Self::freeze(&mut time, &mut rapier, false);
+        tutorial.paused = false;
+        tutorial.resolved = true;
+    }
+
+    /// Moves on to the next step once the player did what the current one asked for, the
+    /// jump steps go back to their prompt after the wrong kind of jump.
+    #[allow(clippy::too_many_arguments)]
+    fn advance(
+        mut commands: Commands,
+        mut tutorial: ResMut<Tutorial>,
+        mut settings: ResMut<Settings>,
+        mut landed: EventReader<Landed>,
+        jumps: Query<&Jump, With<Primary>>,
+        mut collected: EventReader<Collected>,
+        mut dialog: Query<&mut Dialog>,
+        mut delay: Local<Option<Timer>>,
+        time: Res<Time>,
+        localization: Res<Localization>,
+    ) {
+        // a jump let go of within the window gets cut short, `press` starts over
+        let held = landed
+            .read()
+            .filter_map(|event| jumps.get(event.player).ok())
+            .last()
+            .map(|jump| jump.press >= PLAYER_JUMP_WINDOW);
+        let collected = collected.read().count() > 0;
+
+        if !tutorial.resolved {
+            return;
+        }
+
+        let done = match tutorial.step {
+            TutorialStep::Tap => held == Some(false),
+            TutorialStep::Hold => held == Some(true),
+            TutorialStep::Bytes => collected,
+            _ => held.is_some(),
+        };
+
+        let jumping = matches!(tutorial.step, TutorialStep::Tap | TutorialStep::Hold);
+        if jumping && held.is_some() && !done && delay.is_none() {
+            tutorial.resolved = false;
+            return;
+        }
+
+        // wait a little between steps so they don't fire on the very same landing
+        if done && delay.is_none() {
+            *delay = Some(Timer::new(Duration::from_millis(500), TimerMode::Once));
+        }
+
+        let Some(timer) = delay.as_mut() else {
+            return;
+        };
+
+        if !timer.tick(time.delta()).finished() {
+            return;
+        }
+
+        *delay = None;
+        tutorial.step = tutorial.step.next();
+        tutorial.resolved = false;
+
+        if let Some(intro) = tutorial.step.intro() {
+            Self::say(&mut dialog, &localization, intro);
+        }
+
+        if tutorial.step == TutorialStep::Done {
+            settings.tutorial_completed = true;
+            commands.remove_resource::<Tutorial>();
+        }
+    }
+
+    fn skip(
+        mut commands: Commands,
+        mut settings: ResMut<Settings>,
+        mut time: ResMut<Time<Virtual>>,
+        mut rapier: ResMut<RapierConfiguration>,
+        prompts: Query<Entity, With<KeyPrompt>>,
+        input: Res<ButtonInput<KeyCode>>,
+    ) {
+        if !input.just_pressed(KeyCode::Escape) {
+            return;
+        }
+
+        for entity in prompts.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        Self::freeze(&mut time, &mut rapier, false);
+        settings.tutorial_completed = true;
+        commands.remove_resource::<Tutorial>();
+    }
+}
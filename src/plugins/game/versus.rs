@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+use fluent_bundle::FluentArgs;
+
+use crate::{
+    plugins::entities::player::{Being, Died, Memory, Player, PlayerPlugin, Runner, Score},
+    FontsAssets, GameState, Localization, SpriteLayouts, TextureAssets,
+};
+
+/// Local versus, a second runner jumping with its own key and gamepad. Runners falling,
+/// running out of memory or falling too far behind are out, the last one running wins.
+#[derive(Resource, Debug, Default)]
+pub struct Versus {
+    pub active: bool,
+    /// runner that lasted the longest in the last run, `None` for a draw
+    pub winner: Option<usize>,
+}
+
+impl Versus {
+    pub fn runners(&self) -> usize {
+        match self.active {
+            true => 2,
+            false => 1,
+        }
+    }
+}
+
+/// Score and memory of every runner along the bottom of the screen.
+#[derive(Component)]
+struct Scoreboard;
+
+#[derive(Component)]
+struct Banner;
+
+pub struct GameVersusPlugin;
+
+impl Plugin for GameVersusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Versus>()
+            .add_systems(OnEnter(GameState::Resumed), Self::join)
+            .add_systems(OnEnter(Being::Dead), Self::decide)
+            .add_systems(OnExit(Being::Dead), Self::clear)
+            .add_systems(
+                Update,
+                Self::scoreboard
+                    .run_if(in_state(GameState::Resumed))
+                    .run_if(|versus: Res<Versus>| versus.active),
+            );
+    }
+}
+
+impl GameVersusPlugin {
+    /// The first run is set up behind the title screen with a single runner, the
+    /// missing ones join once versus gets picked.
+    fn join(
+        mut commands: Commands,
+        player: Query<&Runner, With<Player>>,
+        versus: Res<Versus>,
+        textures: Res<TextureAssets>,
+        layouts: Res<SpriteLayouts>,
+        fonts: Res<FontsAssets>,
+    ) {
+        if !versus.active {
+            return;
+        }
+
+        for runner in 0..versus.runners() {
+            if !player.iter().any(|joined| joined.0 == runner) {
+                PlayerPlugin::spawn(&mut commands, &textures, &layouts, runner);
+            }
+        }
+
+        commands
+            .spawn(
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: fonts.vcr.clone(),
+                        font_size: 22.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(20.0),
+                    left: Val::Px(20.0),
+                    ..Default::default()
+                }),
+            )
+            .insert(Name::new("Scoreboard"))
+            .insert(Scoreboard);
+    }
+
+    fn scoreboard(
+        player: Query<(&Runner, &Score, &Memory), With<Player>>,
+        mut board: Query<&mut Text, With<Scoreboard>>,
+        localization: Res<Localization>,
+    ) {
+        let Ok(mut board) = board.get_single_mut() else {
+            return;
+        };
+
+        let mut runners = player.iter().collect::<Vec<_>>();
+        runners.sort_by_key(|(runner, ..)| runner.0);
+
+        let value = runners
+            .into_iter()
+            .map(|(runner, score, memory)| {
+                let mut args = FluentArgs::new();
+                args.set("runner", runner.0 + 1);
+                args.set("score", score.value.round());
+                args.set("memory", (memory.fraction() * 100.0).round());
+                localization.format("versus-runner", Some(&args))
+            })
+            .collect::<Vec<_>>()
+            .join("    ");
+
+        if board.sections[0].value != value {
+            board.sections[0].value = value;
+        }
+    }
+
+    /// The last runner out wins the round, runners going out together on the last
+    /// frame make it a draw.
+    fn decide(
+        mut commands: Commands,
+        mut died: EventReader<Died>,
+        mut versus: ResMut<Versus>,
+        fonts: Res<FontsAssets>,
+        localization: Res<Localization>,
+    ) {
+        if !versus.active {
+            return;
+        }
+
+        // runners that went out earlier in the run are long gone from the events
+        let out = died.read().map(|death| death.runner).collect::<Vec<_>>();
+
+        versus.winner = match out.as_slice() {
+            [winner] => Some(*winner),
+            _ => None,
+        };
+
+        let banner = match versus.winner {
+            Some(runner) => {
+                let mut args = FluentArgs::new();
+                args.set("runner", runner + 1);
+                localization.format("versus-winner", Some(&args))
+            }
+            None => localization.get("versus-draw"),
+        };
+
+        commands
+            .spawn(
+                TextBundle::from_section(
+                    banner,
+                    TextStyle {
+                        font: fonts.vcr.clone(),
+                        font_size: 48.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(80.0),
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    ..Default::default()
+                })
+                .with_text_justify(JustifyText::Center),
+            )
+            .insert(Name::new("Versus Banner"))
+            .insert(Banner);
+    }
+
+    fn clear(mut commands: Commands, banner: Query<Entity, With<Banner>>) {
+        for entity in banner.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
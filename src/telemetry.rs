@@ -0,0 +1,138 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils;
+
+/// Folder the game writes its run records to.
+pub fn dir() -> PathBuf {
+    utils::data_dir().join("telemetry")
+}
+
+/// One finished run, written as a single json line once telemetry is turned on.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct RunRecord {
+    pub seed: u64,
+    /// gap scale the run ended at
+    pub difficulty: f32,
+    pub score: f32,
+    /// `fell`, `out-of-memory`, `left-behind` or `forced`
+    pub death_cause: String,
+    /// how far along the world the runner got
+    pub death_x: f32,
+    pub jumps: u32,
+    pub bytes: u32,
+    /// memory left, sampled every `TELEMETRY_SAMPLE_INTERVAL` seconds
+    pub memory: Vec<f32>,
+    /// space between each platform and the one before it
+    pub gaps: Vec<f32>,
+}
+
+impl RunRecord {
+    /// Adds the run at the end of the file at `path`.
+    pub fn append(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        writeln!(file, "{}", serde_json::to_string(self)?)
+    }
+
+    /// Runs recorded in `path`, a file or a folder of them. Lines that don't parse are
+    /// skipped, a run cut short by closing the game leaves half a line behind.
+    pub fn read(path: &Path) -> io::Result<Vec<Self>> {
+        if path.is_dir() {
+            let mut runs = Vec::new();
+
+            for entry in fs::read_dir(path)? {
+                let path = entry?.path();
+
+                if path.extension().is_some_and(|ext| ext == "jsonl") {
+                    runs.extend(Self::read(&path)?);
+                }
+            }
+
+            return Ok(runs);
+        }
+
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect())
+    }
+}
+
+/// Values counted into equally wide bins spanning from the smallest to the largest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub min: f32,
+    pub width: f32,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn new(values: &[f32], bins: usize) -> Option<Self> {
+        let min = values.iter().copied().reduce(f32::min)?;
+        let max = values.iter().copied().reduce(f32::max)?;
+
+        let bins = bins.max(1);
+        // every value being the same still makes for one bin
+        let width = ((max - min) / bins as f32).max(f32::EPSILON);
+        let mut counts = vec![0; bins];
+
+        for value in values {
+            let bin = ((value - min) / width) as usize;
+            counts[bin.min(bins - 1)] += 1;
+        }
+
+        Some(Self { min, width, counts })
+    }
+
+    /// One line per bin with its range, count and a bar `columns` wide at most.
+    pub fn render(&self, columns: usize) -> String {
+        let most = self.counts.iter().copied().max().unwrap_or_default().max(1);
+
+        self.counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let from = self.min + self.width * i as f32;
+                let bar = "#".repeat(count * columns / most);
+
+                format!(
+                    "{:>10.1} .. {:<10.1} {:>6} {bar}",
+                    from,
+                    from + self.width,
+                    count
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test_telemetry {
+    use super::*;
+
+    #[test]
+    fn bins_span_from_the_smallest_to_the_largest_value() {
+        let histogram = Histogram::new(&[0.0, 1.0, 2.5, 9.0, 10.0], 5).unwrap();
+
+        assert_eq!(histogram.min, 0.0);
+        assert_eq!(histogram.width, 2.0);
+        assert_eq!(histogram.counts, [2, 1, 0, 0, 2]);
+        assert!(Histogram::new(&[], 5).is_none());
+        assert_eq!(Histogram::new(&[3.0, 3.0], 4).unwrap().counts, [2, 0, 0, 0]);
+    }
+}